@@ -0,0 +1,122 @@
+//! Membership of many ids in many ranges, as in day 05 part 1, on a generated input.
+//!
+//! ```shell
+//! cargo bench --bench day05 -- [RANGES] [IDS]
+//! ```
+
+use adv_code_2025::Solution;
+use adv_code_2025::bench::{BenchConfig, Stats, measure};
+use adv_code_2025::days::day05::Day05;
+use adv_code_2025::interval::IntervalSet;
+use anyhow::*;
+use std::env;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+/// Ids and range starts are drawn below this bound. The ranges add up to half of it, about
+/// 40% once their overlaps are merged, so about 40% of the ids are fresh.
+const SPACE: u64 = 1 << 40;
+
+/// A fixed linear congruential generator, so every run measures the same input.
+struct Lcg(u64);
+
+impl Lcg {
+    fn below(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 16) % bound
+    }
+}
+
+fn generate(ranges: usize, ids: usize) -> (Vec<RangeInclusive<u64>>, Vec<u64>) {
+    let mut random = Lcg(5);
+    let width = SPACE / ranges as u64;
+    let fresh = (0..ranges)
+        .map(|_| {
+            let start = random.below(SPACE);
+            start..=start + random.below(width)
+        })
+        .collect();
+    let available = (0..ids).map(|_| random.below(SPACE)).collect();
+    (fresh, available)
+}
+
+/// Prints a row of timings, with the number of fresh ids found when the step counts them.
+fn report(name: &str, stats: &Stats, fresh: Option<usize>) {
+    print!(
+        "{:<14} {:>12?} {:>12?} {:>12?}",
+        name, stats.min, stats.median, stats.p95
+    );
+    match fresh {
+        Some(fresh) => println!("  {} fresh", fresh),
+        None => println!(),
+    }
+}
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1).filter(|a| a != "--bench");
+    let ranges: usize = args.next().map_or(Result::Ok(100_000), |a| a.parse())?;
+    let ids: usize = args.next().map_or(Result::Ok(1_000_000), |a| a.parse())?;
+    let (fresh, available) = generate(ranges, ids);
+    let set = fresh.iter().cloned().collect::<IntervalSet<_>>();
+    println!(
+        "{} ranges ({} once merged), {} ids",
+        ranges,
+        set.intervals().len(),
+        ids
+    );
+    println!("{:<14} {:>12} {:>12} {:>12}", "", "min", "median", "p95");
+
+    // The former approach, every id against every range: only measured on a sample.
+    let sample = &available[..available.len().min(1_000)];
+    let linear = measure(&BenchConfig::default(), || {
+        Ok(sample
+            .iter()
+            .filter(|&id| fresh.iter().any(|r| r.contains(id)))
+            .count())
+    })?;
+    let found = sample
+        .iter()
+        .filter(|&id| fresh.iter().any(|r| r.contains(id)))
+        .count();
+    report(&format!("linear ({})", sample.len()), &linear, Some(found));
+
+    let config = BenchConfig::default();
+    let search = measure(&config, || {
+        Ok(available.iter().filter(|&&id| set.contains(id)).count())
+    })?;
+    let found = available.iter().filter(|&&id| set.contains(id)).count();
+    report("binary search", &search, Some(found));
+
+    let mut sorted = available.clone();
+    sorted.sort_unstable();
+    let sweep = measure(&config, || {
+        Ok(set
+            .contains_each(&sorted)
+            .into_iter()
+            .filter(|&b| b)
+            .count())
+    })?;
+    report("sorted sweep", &sweep, Some(found));
+
+    // Parsing alone, then the whole part, parsing included.
+    let mut input = String::new();
+    for r in &fresh {
+        writeln!(input, "{}-{}", r.start(), r.end())?;
+    }
+    input.push('\n');
+    for id in &available {
+        writeln!(input, "{}", id)?;
+    }
+    let parse = measure(&config, || Day05::parse(input.as_bytes()))?;
+    report("day 05 parse", &parse, None);
+    let part1 = measure(&config, || Day05::part1(&Day05::parse(input.as_bytes())?))?;
+    report(
+        "day 05 part 1",
+        &part1,
+        Some(Day05::part1(&Day05::parse(input.as_bytes())?)?),
+    );
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use crate::runner::Part;
+use anyhow::*;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Confirmed answers of a day, stored in `answers/<DAY>.toml`:
+///
+/// ```toml
+/// part1 = 3
+/// part2 = "14"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verification {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verification::Pass => write!(f, "PASS"),
+            Verification::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verification::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn path(day: u8) -> PathBuf {
+        PathBuf::from(format!("answers/{:0>2}.toml", day))
+    }
+
+    /// Loads the answers of `day`; a missing file means nothing is confirmed yet.
+    pub fn load(day: u8) -> Result<Self> {
+        Self::load_from(&Self::path(day))
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content =
+            fs::read_to_string(path).with_context(|| format!("Cannot read {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let table = content.parse::<toml::Table>()?;
+        let answer = |key: &str| -> Result<Option<String>> {
+            match table.get(key) {
+                None => Ok(None),
+                Some(toml::Value::String(s)) => Ok(Some(s.clone())),
+                Some(toml::Value::Integer(i)) => Ok(Some(i.to_string())),
+                Some(other) => Err(anyhow!(
+                    "Expected a string or an integer for {}, got {}",
+                    key,
+                    other
+                )),
+            }
+        };
+        Ok(Self {
+            part1: answer("part1")?,
+            part2: answer("part2")?,
+        })
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        let mut table = toml::Table::new();
+        for (key, answer) in [("part1", &self.part1), ("part2", &self.part2)] {
+            if let Some(answer) = answer {
+                table.insert(key.to_string(), toml::Value::String(answer.clone()));
+            }
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, table.to_string())
+            .with_context(|| format!("Cannot write {}", path.display()))
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: &str) {
+        let answer = Some(answer.to_string());
+        match part {
+            Part::One => self.part1 = answer,
+            Part::Two => self.part2 = answer,
+        }
+    }
+
+    pub fn verify(&self, part: Part, answer: &str) -> Verification {
+        match self.expected(part) {
+            None => Verification::Unknown,
+            Some(expected) if expected == answer => Verification::Pass,
+            Some(expected) => Verification::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn parse_accepts_strings_and_integers() -> Result<()> {
+        let answers = Answers::parse("part1 = 3\npart2 = \"14\"\n")?;
+        assert_eq!(Some("3"), answers.expected(Part::One));
+        assert_eq!(Some("14"), answers.expected(Part::Two));
+        assert!(Answers::parse("part1 = 1.5").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn verify_reports_pass_fail_unknown() -> Result<()> {
+        let answers = Answers::parse("part1 = 3")?;
+        assert_eq!(Verification::Pass, answers.verify(Part::One, "3"));
+        assert_eq!(
+            Verification::Fail {
+                expected: "3".to_string()
+            },
+            answers.verify(Part::One, "4")
+        );
+        assert_eq!(Verification::Unknown, answers.verify(Part::Two, "14"));
+        Ok(())
+    }
+
+    #[test]
+    fn save_round_trips() -> Result<()> {
+        let dir = TempDir::new("answers");
+        let path = dir.join("05.toml");
+        let mut answers = Answers::default();
+        answers.set(Part::Two, "14");
+        answers.save_to(&path)?;
+        assert_eq!(answers, Answers::load_from(&path)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+//! Cellular automata over a [`Grid<bool>`] of live and dead cells.
+
+use crate::grid::{Grid, ORTHOGONAL, Pos, SURROUNDING};
+use std::collections::VecDeque;
+
+/// The cells whose state a cell looks at, as `(row, column)` offsets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The 4 orthogonal neighbors.
+    VonNeumann,
+    /// The 8 surrounding cells.
+    Moore,
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighborhood {
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        match self {
+            Neighborhood::VonNeumann => &ORTHOGONAL,
+            Neighborhood::Moore => &SURROUNDING,
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
+
+    /// The cells looking at a cell, rather than the cells it looks at.
+    ///
+    /// Both are the same for the symmetric neighborhoods, not for custom ones.
+    pub fn reversed(&self) -> Self {
+        match self {
+            Neighborhood::Custom(offsets) => {
+                Neighborhood::Custom(offsets.iter().map(|&(r, c)| (-r, -c)).collect())
+            }
+            symmetric => symmetric.clone(),
+        }
+    }
+}
+
+/// What lies beyond the borders of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// Nothing: border cells have fewer neighbors.
+    Bounded,
+    /// The opposite border, as if the grid were wrapped on a torus.
+    Toroidal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Automaton {
+    pub neighborhood: Neighborhood,
+    pub edges: Edges,
+}
+
+impl Automaton {
+    pub fn neighbors<'a, T>(
+        &'a self,
+        grid: &'a Grid<T>,
+        (row, col): Pos,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        let offsets = self.neighborhood.offsets();
+        offsets.iter().filter_map(move |&offset| match self.edges {
+            Edges::Bounded => grid.offset((row, col), offset),
+            Edges::Toroidal if grid.width() == 0 || grid.height() == 0 => None,
+            Edges::Toroidal => Some((
+                wrap(row, offset.0, grid.height()),
+                wrap(col, offset.1, grid.width()),
+            )),
+        })
+    }
+
+    pub fn live_neighbors(&self, grid: &Grid<bool>, pos: Pos) -> usize {
+        self.neighbors(grid, pos).filter(|&p| grid[p]).count()
+    }
+
+    /// Computes the next generation, all cells at once: `rule` gets the state of a cell and
+    /// its number of live neighbors, and returns the new state.
+    pub fn step(&self, grid: &Grid<bool>, rule: impl Fn(bool, usize) -> bool) -> Grid<bool> {
+        let mut next = grid.clone();
+        for (pos, &alive) in grid.iter() {
+            next[pos] = rule(alive, self.live_neighbors(grid, pos));
+        }
+        next
+    }
+}
+
+fn wrap(index: usize, offset: isize, size: usize) -> usize {
+    (index as isize + offset).rem_euclid(size as isize) as usize
+}
+
+/// An automaton where live cells with fewer than `threshold` live neighbors die, and no
+/// cell is ever born.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Erosion {
+    pub automaton: Automaton,
+    pub threshold: usize,
+}
+
+impl Erosion {
+    pub const fn new(neighborhood: Neighborhood, edges: Edges, threshold: usize) -> Self {
+        Self {
+            automaton: Automaton {
+                neighborhood,
+                edges,
+            },
+            threshold,
+        }
+    }
+
+    /// Live cells dying in the next step.
+    pub fn eroded(&self, grid: &Grid<bool>) -> Vec<Pos> {
+        grid.iter()
+            .filter(|&(pos, &alive)| {
+                alive && self.automaton.live_neighbors(grid, pos) < self.threshold
+            })
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    pub fn step(&self, grid: &Grid<bool>) -> Grid<bool> {
+        self.automaton
+            .step(grid, |alive, live| alive && live >= self.threshold)
+    }
+
+    /// The step in which each cell dies when the erosion runs until it is stable: `Some(0)`
+    /// for the cells dying in the first step, and so on. `None` for dead cells and for
+    /// cells that survive.
+    ///
+    /// Live neighbor counts are computed once then decremented as neighbors die, so each
+    /// cell is queued at most once: the whole run is linear in the number of cells.
+    pub fn waves(&self, grid: &Grid<bool>) -> Grid<Option<usize>> {
+        let watchers = Automaton {
+            neighborhood: self.automaton.neighborhood.reversed(),
+            edges: self.automaton.edges,
+        };
+        let mut counts = grid.map(|_| 0);
+        let mut waves = grid.map(|_| None);
+        let mut queue = VecDeque::new();
+        for (pos, &alive) in grid.iter() {
+            if alive {
+                counts[pos] = self.automaton.live_neighbors(grid, pos);
+                if counts[pos] < self.threshold {
+                    waves[pos] = Some(0);
+                    queue.push_back((pos, 0));
+                }
+            }
+        }
+        while let Some((pos, wave)) = queue.pop_front() {
+            for watcher in watchers.neighbors(grid, pos) {
+                if !grid[watcher] || waves[watcher].is_some() {
+                    continue;
+                }
+                counts[watcher] -= 1;
+                if counts[watcher] < self.threshold {
+                    waves[watcher] = Some(wave + 1);
+                    queue.push_back((watcher, wave + 1));
+                }
+            }
+        }
+        waves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::*;
+
+    fn grid(map: &str) -> Result<Grid<bool>> {
+        Grid::parse(map.as_bytes(), "# or .", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+
+    fn show(grid: &Grid<bool>) -> String {
+        grid.display(|&alive| if alive { '#' } else { '.' })
+            .to_string()
+    }
+
+    fn life(alive: bool, live: usize) -> bool {
+        live == 3 || alive && live == 2
+    }
+
+    #[test]
+    fn moore_bounded_life_blinks() -> Result<()> {
+        let life_automaton = Automaton {
+            neighborhood: Neighborhood::Moore,
+            edges: Edges::Bounded,
+        };
+        let blinker = grid(".....\n..#..\n..#..\n..#..\n.....\n")?;
+        let next = life_automaton.step(&blinker, life);
+        assert_eq!(".....\n.....\n.###.\n.....\n.....\n", show(&next));
+        assert_eq!(blinker, life_automaton.step(&next, life));
+        Ok(())
+    }
+
+    #[test]
+    fn toroidal_glider_comes_back() -> Result<()> {
+        let life_automaton = Automaton {
+            neighborhood: Neighborhood::Moore,
+            edges: Edges::Toroidal,
+        };
+        let glider = grid(".#....\n..#...\n###...\n......\n......\n......\n")?;
+        assert_eq!(8, life_automaton.neighbors(&glider, (0, 0)).count());
+        let mut current = glider.clone();
+        for _ in 0..24 {
+            current = life_automaton.step(&current, life);
+        }
+        assert_eq!(glider, current);
+        Ok(())
+    }
+
+    #[test]
+    fn von_neumann_counts_orthogonal_neighbors() -> Result<()> {
+        let full = grid("###\n###\n###\n")?;
+        let bounded = Automaton {
+            neighborhood: Neighborhood::VonNeumann,
+            edges: Edges::Bounded,
+        };
+        assert_eq!(4, bounded.live_neighbors(&full, (1, 1)));
+        assert_eq!(2, bounded.live_neighbors(&full, (0, 0)));
+        let toroidal = Automaton {
+            edges: Edges::Toroidal,
+            ..bounded
+        };
+        assert_eq!(4, toroidal.live_neighbors(&full, (0, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn custom_offsets_shift_cells() -> Result<()> {
+        let copy_left = Automaton {
+            neighborhood: Neighborhood::Custom(vec![(0, -1)]),
+            edges: Edges::Toroidal,
+        };
+        let row = grid("#..#.\n")?;
+        let next = copy_left.step(&row, |_, live| live == 1);
+        assert_eq!(".#..#\n", show(&next));
+        assert_eq!("#.#..\n", show(&copy_left.step(&next, |_, live| live == 1)));
+        Ok(())
+    }
+
+    /// Runs the erosion step by step until nothing changes.
+    fn naive_waves(erosion: &Erosion, grid: &Grid<bool>) -> Grid<Option<usize>> {
+        let mut current = grid.clone();
+        let mut waves = grid.map(|_| None);
+        for wave in 0.. {
+            let eroded = erosion.eroded(&current);
+            if eroded.is_empty() {
+                break;
+            }
+            for &pos in &eroded {
+                waves[pos] = Some(wave);
+            }
+            current = erosion.step(&current);
+        }
+        waves
+    }
+
+    #[test]
+    fn waves_match_step_by_step_erosion() -> Result<()> {
+        let rules = [
+            Erosion::new(Neighborhood::Moore, Edges::Bounded, 4),
+            Erosion::new(Neighborhood::Moore, Edges::Toroidal, 5),
+            Erosion::new(Neighborhood::VonNeumann, Edges::Bounded, 2),
+            Erosion::new(Neighborhood::VonNeumann, Edges::Toroidal, 3),
+            Erosion::new(
+                Neighborhood::Custom(vec![(0, 1), (1, 1), (-2, 0)]),
+                Edges::Toroidal,
+                2,
+            ),
+        ];
+        // A fixed pseudo-random fill, about two thirds alive.
+        let mut seed = 17u64;
+        let mut random = Grid::new(12, 9, false);
+        for pos in random.positions() {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            random[pos] = !(seed >> 33).is_multiple_of(3);
+        }
+        for rule in &rules {
+            assert_eq!(
+                naive_waves(rule, &random),
+                rule.waves(&random),
+                "{:?}",
+                rule
+            );
+        }
+        Ok(())
+    }
+}
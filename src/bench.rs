@@ -0,0 +1,304 @@
+use crate::Solution;
+use crate::runner::Part;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Where `--bench` results are appended, to compare runs over time.
+pub const HISTORY_FILE: &str = "bench/history.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Step {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Step::Part1,
+            Part::Two => Step::Part2,
+        }
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Step::Parse => "parse",
+            Step::Part1 => "part1",
+            Step::Part2 => "part2",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs before measuring, to warm caches and the allocator up.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort();
+        Self {
+            iterations: samples.len(),
+            min: samples.first().copied().unwrap_or_default(),
+            median: percentile(samples, 50),
+            p95: percentile(samples, 95),
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Times `f` over `config.iterations` runs, after `config.warmup` untimed ones.
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        black_box(f()?);
+    }
+    let mut samples = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations.max(1) {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(&mut samples))
+}
+
+pub type Timings = Vec<(Step, Stats)>;
+
+/// Benchmarks parsing `input`, then each of `parts` on the parsed input.
+pub fn bench_solution<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    config: &BenchConfig,
+) -> Result<Timings> {
+    let mut results = vec![(Step::Parse, measure(config, || S::parse(input.as_bytes()))?)];
+    let parsed = S::parse(input.as_bytes())?;
+    for &part in parts {
+        let stats = match part {
+            Part::One => measure(config, || S::part1(&parsed))?,
+            Part::Two => measure(config, || S::part2(&parsed))?,
+        };
+        results.push((Step::from(part), stats));
+    }
+    Ok(results)
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchResult {
+    pub day: u8,
+    pub input: String,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+/// One line of the history file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// Unix time of the run.
+    pub timestamp: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub day: u8,
+    pub input: String,
+    pub step: Step,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Record {
+    fn matches(&self, result: &BenchResult) -> bool {
+        self.day == result.day && self.input == result.input && self.step == result.step
+    }
+}
+
+pub struct History {
+    path: PathBuf,
+    pub records: Vec<Record>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        let records = if path.exists() {
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("Cannot read {}", path.display()))?;
+            serde_json::from_str(&content)
+                .with_context(|| format!("Invalid bench history {}", path.display()))?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            records,
+        })
+    }
+
+    /// The latest recorded measure of the same day, input and step.
+    pub fn previous(&self, result: &BenchResult) -> Option<&Record> {
+        self.records.iter().rev().find(|r| r.matches(result))
+    }
+
+    pub fn append(&mut self, results: &[BenchResult], label: Option<&str>, now: SystemTime) {
+        let timestamp = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        self.records.extend(results.iter().map(|r| Record {
+            timestamp,
+            label: label.map(str::to_string),
+            day: r.day,
+            input: r.input.clone(),
+            step: r.step,
+            iterations: r.stats.iterations,
+            min_ns: nanos(r.stats.min),
+            median_ns: nanos(r.stats.median),
+            p95_ns: nanos(r.stats.p95),
+        }));
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self.records)?)
+            .with_context(|| format!("Cannot write {}", self.path.display()))
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Prints the results, with the median change since the previous run in `history`.
+pub fn print_bench(results: &[BenchResult], history: &History) {
+    let input_width = results
+        .iter()
+        .map(|r| r.input.len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    println!(
+        "Day  {:<input_width$}  Step   {:>12}  {:>12}  {:>12}  vs previous",
+        "Input", "Min", "Median", "P95"
+    );
+    for result in results {
+        let change = match history.previous(result) {
+            Some(previous) if previous.median_ns > 0 => {
+                let ratio = nanos(result.stats.median) as f64 / previous.median_ns as f64;
+                format!("{:+.1}%", (ratio - 1.0) * 100.0)
+            }
+            _ => "-".to_string(),
+        };
+        println!(
+            "{:0>2}   {:<input_width$}  {:<5}  {:>12}  {:>12}  {:>12}  {}",
+            result.day,
+            result.input,
+            result.step,
+            format!("{:?}", result.stats.min),
+            format!("{:?}", result.stats.median),
+            format!("{:?}", result.stats.p95),
+            change
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn stats_use_nearest_rank() {
+        let stats = Stats::from_samples(&mut millis(&[9, 1, 5, 3, 7, 2, 8, 4, 6, 10]));
+        assert_eq!(10, stats.iterations);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(5), stats.median);
+        assert_eq!(Duration::from_millis(10), stats.p95);
+
+        let single = Stats::from_samples(&mut millis(&[4]));
+        assert_eq!(
+            (single.min, single.median, single.p95),
+            (single.p95, single.p95, single.p95)
+        );
+    }
+
+    #[test]
+    fn measure_runs_warmup_then_iterations() -> Result<()> {
+        let mut calls = 0;
+        let config = BenchConfig {
+            warmup: 2,
+            iterations: 5,
+        };
+        let stats = measure(&config, || {
+            calls += 1;
+            Ok(calls)
+        })?;
+        assert_eq!(7, calls);
+        assert_eq!(5, stats.iterations);
+        Ok(())
+    }
+
+    #[test]
+    fn history_round_trips_and_finds_previous() -> Result<()> {
+        let dir = TempDir::new("bench");
+        let path = dir.join("bench.json");
+        let result = |median| BenchResult {
+            day: 8,
+            input: "main".to_string(),
+            step: Step::Part1,
+            stats: Stats {
+                iterations: 10,
+                min: Duration::from_micros(median - 1),
+                median: Duration::from_micros(median),
+                p95: Duration::from_micros(median + 1),
+            },
+        };
+
+        let mut history = History::load(&path)?;
+        history.append(&[result(100)], Some("before"), UNIX_EPOCH);
+        history.append(&[result(50)], None, UNIX_EPOCH);
+        history.save()?;
+
+        let history = History::load(&path)?;
+        assert_eq!(2, history.records.len());
+        assert_eq!(Some("before"), history.records[0].label.as_deref());
+        assert_eq!(50_000, history.previous(&result(10)).unwrap().median_ns);
+        Ok(())
+    }
+}
@@ -1,116 +1,84 @@
+use adv_code_2025::days::day01::{
+    Day01, Dial, Rotate, TraceFormat, reversed, run_dials, trace, write_trace,
+};
+use adv_code_2025::input::InputSource;
 use adv_code_2025::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::str::FromStr;
-
-const DAY: &str = "01";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
-
-const TEST: &str = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
-
-#[derive(Debug)]
-struct Rotate {
-    direction: i32,
-    steps: usize,
-}
-
-impl Rotate {
-    fn list<R: BufRead>(reader: R) -> Result<Vec<Self>> {
-        reader.lines().map(|line| Self::from_str(&line?)).collect()
-    }
-    fn rotate(&self, current: usize) -> (usize, usize) {
-        let natural_turn = self.steps / 100;
-        let steps = self.steps % 100;
-
-        let delta = self.direction * steps as i32;
-        let computed = (current as i32) + delta;
-        let local_steps = computed.abs() % 100;
-        let result = if computed < 0 {
-            100 - local_steps
-        } else if computed > 99 {
-            local_steps
-        } else {
-            computed
-        };
-
-        let zero_count = match self.direction {
-            1 => usize::from(computed > 100),
-            -1 => usize::from(current == 0 && steps > 99 || current != 0 && steps > current),
-            _ => 0,
-        };
-        (
-            result as usize,
-            natural_turn + usize::from(result == 0) + zero_count,
-        )
-    }
-}
-
-impl FromStr for Rotate {
-    type Err = Error;
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let steps = usize::from_str(&s[1..])?;
-        let direction = match &s[0..1] {
-            "L" => -1,
-            "R" => 1,
-            _ => unreachable!(),
-        };
-        Ok(Self { direction, steps })
-    }
+use clap::{ArgGroup, Parser};
+use std::io::stdout;
+
+/// Runs day 01, or traces the dial rotation by rotation.
+#[derive(Parser, Debug)]
+#[command(group(ArgGroup::new("mode").args(["trace", "dials", "compose"])))]
+struct Args {
+    /// Print the position of the dial and its clicks on 0 after each rotation instead of
+    /// the answers.
+    #[arg(long)]
+    trace: bool,
+
+    /// Stop the trace after this rotation, numbered from 1 like the input lines.
+    #[arg(long, value_name = "INDEX", requires = "trace")]
+    until: Option<usize>,
+
+    /// How to print the trace.
+    #[arg(long, value_enum, default_value_t = TraceFormat::Table, requires = "trace")]
+    format: TraceFormat,
+
+    /// Turn a dial of SIZE positions starting at START with the rotations, and print its
+    /// totals; may be repeated to drive several dials at once.
+    #[arg(long = "dial", value_name = "SIZE:START")]
+    dials: Vec<Dial>,
+
+    /// Print the single rotation ending where all the rotations end.
+    #[arg(long)]
+    compose: bool,
+
+    /// Undo the rotations: apply their inverses, last one first.
+    #[arg(long, requires = "mode")]
+    reverse: bool,
+
+    /// Read this file instead of `input/01.txt` (`-` for stdin).
+    #[arg(short, long, value_name = "PATH", requires = "mode")]
+    input: Option<String>,
 }
 
 fn main() -> Result<()> {
-    start_day(DAY);
-
-    //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let items = Rotate::list(reader)?;
-        let result = items.iter().fold((50, 0), |(pos, counts), item| {
-            let current = item.rotate(pos);
-            (current.0, counts + usize::from(current.0 == 0))
-        });
-        Ok(result.1)
+    let args = Args::parse();
+    if !args.trace && args.dials.is_empty() && !args.compose {
+        return run::<Day01>();
     }
-
-    // TODO: Set the expected answer for the test input
-    assert_eq!(3, part1(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let items = Rotate::list(reader)?;
-        let result = items.iter().fold((50, 0), |(pos, counts), item| {
-            let current = item.rotate(pos);
-            (current.0, counts + current.1)
-        });
-        Ok(result.1)
+    let source = match &args.input {
+        Some(arg) => InputSource::from_arg(arg),
+        None => InputSource::for_day(1),
+    };
+    let mut rotations = Day01::parse(source.open()?)?;
+    if args.reverse {
+        rotations = reversed(&rotations);
     }
 
-    assert_eq!(6, part2(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
+    if args.trace {
+        write_trace(
+            &mut stdout().lock(),
+            &trace(&rotations, args.until),
+            args.format,
+        )?;
+    } else if args.compose {
+        println!("{}", Rotate::compose(&rotations)?);
+    } else {
+        let mut dials = args.dials.clone();
+        let stats = run_dials(&mut dials, &rotations);
+        println!(" Size  Start   End  Landed  Crossed   Hits");
+        for ((start, end), stats) in args.dials.iter().zip(&dials).zip(&stats) {
+            println!(
+                "{:>5}  {:>5}  {:>4}  {:>6}  {:>7}  {:>5}",
+                start.size(),
+                start.position(),
+                end.position(),
+                stats.landed,
+                stats.crossed,
+                stats.zero_hits
+            );
+        }
+    }
     Ok(())
 }
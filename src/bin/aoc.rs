@@ -0,0 +1,270 @@
+use adv_code_2025::answers::Answers;
+use adv_code_2025::bench::{BenchConfig, HISTORY_FILE, History, print_bench};
+use adv_code_2025::client::AocClient;
+use adv_code_2025::days;
+use adv_code_2025::format::{Format, print_reports};
+use adv_code_2025::input::{InputSource, NamedInput, input_dir};
+use adv_code_2025::provider::InputProvider;
+use adv_code_2025::runner::{Part, PartReport, parse_days};
+use adv_code_2025::scaffold::Scaffold;
+use adv_code_2025::submit::{Submissions, Verdict};
+use adv_code_2025::visual::{self, Export};
+use anyhow::*;
+use clap::{Parser, Subcommand};
+use std::fs::File;
+use std::io::{BufWriter, stdout};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Runs the Advent of Code 2025 solutions.
+#[derive(Parser, Debug)]
+#[command(name = "aoc")]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day or inclusive range of days to run (`5`, `3..7`); may be repeated.
+    /// Defaults to the latest day.
+    #[arg(short, long = "day", value_parser = parse_days, conflicts_with = "all")]
+    days: Vec<RangeInclusive<u8>>,
+
+    /// Only run this part (1 or 2).
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run every registered day.
+    #[arg(short, long)]
+    all: bool,
+
+    /// Read the input from this file instead of `input/NN.txt` (`-` for stdin).
+    /// Requires a single selected day.
+    #[arg(short, long, value_name = "PATH", conflicts_with = "all_inputs")]
+    input: Option<String>,
+
+    /// Download missing main inputs into `input/` (needs `AOC_SESSION`).
+    /// Inputs already there are never fetched again.
+    #[arg(long, conflicts_with = "input")]
+    fetch: bool,
+
+    /// Run each day against every input found in `input/NN/*.txt`, not only the main one.
+    #[arg(long)]
+    all_inputs: bool,
+
+    /// Exit with an error if an answer differs from the one confirmed in `answers/`.
+    #[arg(long)]
+    verify: bool,
+
+    /// Submit the answer of the selected day and part (needs `AOC_SESSION`),
+    /// unless it is already known to be wrong.
+    #[arg(long, requires = "part", conflicts_with_all = ["all", "input", "all_inputs"])]
+    submit: bool,
+
+    /// How to print the results.
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Benchmark parsing and each part instead of printing answers, and record the
+    /// results in `bench/history.json`.
+    #[arg(long, conflicts_with_all = ["submit", "verify"])]
+    bench: bool,
+
+    /// Timed runs of each step in `--bench` mode.
+    #[arg(long, default_value_t = BenchConfig::default().iterations, requires = "bench")]
+    iterations: usize,
+
+    /// Untimed runs of each step before measuring in `--bench` mode.
+    #[arg(long, default_value_t = BenchConfig::default().warmup, requires = "bench")]
+    warmup: usize,
+
+    /// Name stored with the `--bench` results, e.g. the change being measured.
+    #[arg(long, requires = "bench")]
+    label: Option<String>,
+
+    /// Record how the state evolves instead of printing answers, and export it.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "EXPORT",
+        conflicts_with_all = ["bench", "submit", "verify"]
+    )]
+    visualize: Option<Export>,
+
+    /// Where `--visualize` writes the images (a directory) or the GIF.
+    /// Defaults to `visual/NN` or `visual/NN.gif`; requires a single day and input.
+    #[arg(long, value_name = "PATH", requires = "visualize")]
+    output: Option<PathBuf>,
+
+    /// Size in pixels of a cell in `--visualize` images.
+    #[arg(long, default_value_t = 4, requires = "visualize")]
+    scale: usize,
+
+    /// Milliseconds between the frames of a `--visualize` animation.
+    #[arg(long, default_value_t = 100, requires = "visualize")]
+    delay: u64,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Create the module, binary, input and answers files of a new day and register it.
+    Scaffold {
+        /// Day to create (1 to 25).
+        day: u8,
+    },
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    if let Some(Command::Scaffold { day }) = args.command {
+        for path in Scaffold::new(".", input_dir()).create(day)? {
+            println!("Wrote {}", path.display());
+        }
+        return Ok(());
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![Part::from_number(part)?],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut selected = days::all();
+    if !args.all {
+        if args.days.is_empty() {
+            selected = selected.split_off(selected.len() - 1);
+        } else {
+            selected.retain(|d| args.days.iter().any(|range| range.contains(&d.number)));
+        }
+    }
+    if selected.is_empty() {
+        return Err(anyhow!("No registered day matches the selection"));
+    }
+
+    let adhoc = args.input.as_deref().map(InputSource::from_arg);
+    if adhoc.is_some() && selected.len() > 1 {
+        return Err(anyhow!(
+            "--input requires a single day, {} selected",
+            selected.len()
+        ));
+    }
+
+    if args.fetch {
+        let provider = InputProvider::new(input_dir(), AocClient::from_env()?);
+        for day in &selected {
+            provider.get(day.number)?;
+        }
+    }
+
+    let mut runs = Vec::new();
+    for day in &selected {
+        let inputs = match &adhoc {
+            Some(source) => vec![NamedInput::adhoc(source.clone())],
+            None if args.all_inputs => NamedInput::discover(day.number)?,
+            None => vec![NamedInput::main(day.number)],
+        };
+        runs.extend(inputs.into_iter().map(|input| (day, input)));
+    }
+
+    if args.bench {
+        let config = BenchConfig {
+            warmup: args.warmup,
+            iterations: args.iterations,
+        };
+        let mut results = Vec::new();
+        for (day, input) in &runs {
+            results.extend(day.bench(input, &parts, &config)?);
+        }
+        let mut history = History::load(Path::new(HISTORY_FILE))?;
+        print_bench(&results, &history);
+        history.append(&results, args.label.as_deref(), SystemTime::now());
+        return history.save();
+    }
+
+    if let Some(export) = args.visualize {
+        if args.output.is_some() && runs.len() > 1 {
+            return Err(anyhow!(
+                "--output requires a single day and input, {} selected",
+                runs.len()
+            ));
+        }
+        let delay = Duration::from_millis(args.delay);
+        for (day, input) in &runs {
+            let recording = day.visualize(input)?;
+            let output = args.output.clone().unwrap_or_else(|| {
+                let mut name = format!("visual/{:0>2}", day.number);
+                if runs.len() > 1 {
+                    name = format!("{}-{}", name, input.name);
+                }
+                if export == Export::Gif {
+                    name.push_str(".gif");
+                }
+                PathBuf::from(name)
+            });
+            match export {
+                Export::Ascii => recording.play(&mut stdout(), delay)?,
+                Export::Gif => {
+                    if let Some(dir) = output.parent() {
+                        std::fs::create_dir_all(dir)?;
+                    }
+                    let file = File::create(&output)
+                        .with_context(|| format!("Cannot write {}", output.display()))?;
+                    recording.write_gif(
+                        BufWriter::new(file),
+                        args.scale,
+                        delay,
+                        visual::palette,
+                    )?;
+                    println!("Wrote {}", output.display());
+                }
+                Export::Ppm | Export::Png => {
+                    let paths =
+                        recording.save_images(&output, export, args.scale, visual::palette)?;
+                    println!("Wrote {} frames to {}", paths.len(), output.display());
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let mut reports = Vec::new();
+    for (day, input) in &runs {
+        reports.extend(day.run(input, &parts));
+    }
+    print_reports(&reports, args.format)?;
+
+    if args.submit {
+        submit(&reports)?;
+    }
+
+    let failures = reports.iter().filter(|r| r.is_failure()).count();
+    if args.verify && failures > 0 {
+        return Err(anyhow!("{} part(s) failed verification", failures));
+    }
+
+    Ok(())
+}
+
+/// Submits the single computed answer, recording it in `answers/` once accepted.
+fn submit(reports: &[PartReport]) -> Result<()> {
+    let [report] = reports else {
+        return Err(anyhow!(
+            "--submit requires a single day and part, {} answers computed",
+            reports.len()
+        ));
+    };
+    let answer = report
+        .answer
+        .as_ref()
+        .map_err(|e| anyhow!("Nothing to submit: {}", e))?;
+    let mut submissions = Submissions::open(report.day, AocClient::from_env()?)?;
+    let verdict = submissions.submit(report.part, answer)?;
+    println!(
+        "Day {:0>2} part {}: {} is {}",
+        report.day, report.part, answer, verdict
+    );
+    if verdict == Verdict::Correct {
+        let mut answers = Answers::load(report.day)?;
+        answers.set(report.part, answer);
+        answers.save_to(&Answers::path(report.day))?;
+    }
+    Ok(())
+}
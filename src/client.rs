@@ -0,0 +1,101 @@
+use anyhow::*;
+use std::env;
+
+/// Environment variable holding the `session` cookie of an adventofcode.com login.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub const YEAR: u16 = 2025;
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/dohr-michael/advent-of-code-2025";
+
+/// HTTP client for the website, authenticated with a session cookie.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(session: &str) -> Self {
+        Self::with_base_url(BASE_URL, session)
+    }
+
+    /// A client for another server than adventofcode.com, e.g. a local stand-in.
+    pub fn with_base_url(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::Agent::new_with_defaults(),
+        }
+    }
+
+    /// Reads the session cookie from `$AOC_SESSION`.
+    pub fn from_env() -> Result<Self> {
+        let session = env::var(SESSION_VAR).with_context(|| {
+            format!(
+                "{} must hold your adventofcode.com session cookie",
+                SESSION_VAR
+            )
+        })?;
+        Ok(Self::new(&session))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}{}", self.base_url, YEAR, path)
+    }
+
+    /// `GET /2025<path>`, returning the body of a successful response.
+    pub fn get(&self, path: &str) -> Result<String> {
+        let url = self.url(path);
+        let body = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .with_context(|| format!("Cannot fetch {}", url))?
+            .body_mut()
+            .read_to_string()?;
+        Ok(body)
+    }
+
+    /// `POST /2025<path>` with a url-encoded form, returning the body of a successful response.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = self.url(path);
+        let body = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())
+            .with_context(|| format!("Cannot post to {}", url))?
+            .body_mut()
+            .read_to_string()?;
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockServer;
+
+    #[test]
+    fn requests_carry_session_and_user_agent() -> Result<()> {
+        let server =
+            MockServer::start(|request| (200, format!("{} {}", request.method, request.path)));
+        let client = AocClient::with_base_url(&format!("{}/", server.url), " secret\n");
+
+        assert_eq!("GET /2025/day/1/input", client.get("/day/1/input")?);
+        assert_eq!(
+            "POST /2025/day/1/answer",
+            client.post_form("/day/1/answer", &[("level", "1"), ("answer", "42")])?
+        );
+        let requests = server.requests();
+        assert_eq!(Some("session=secret"), requests[0].header("Cookie"));
+        assert_eq!(Some(USER_AGENT), requests[1].header("User-Agent"));
+        assert_eq!("level=1&answer=42", requests[1].body);
+        Ok(())
+    }
+}
@@ -0,0 +1,481 @@
+use crate::Solution;
+use crate::parse::{ParseError, lines, number};
+use anyhow::*;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+pub const TEST: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotate {
+    /// -1 for left, towards lower numbers, 1 for right.
+    direction: i32,
+    steps: usize,
+}
+
+impl Rotate {
+    fn list<R: BufRead>(reader: R) -> Result<Vec<Self>> {
+        lines(reader)?
+            .iter()
+            .enumerate()
+            .map(|(i, line)| Ok(Self::from_str(line).map_err(|e| e.at_line(i + 1))?))
+            .collect()
+    }
+
+    /// The rotation undoing this one.
+    pub fn inverse(&self) -> Self {
+        Self {
+            direction: -self.direction,
+            steps: self.steps,
+        }
+    }
+
+    /// A single rotation ending where `rotations` end, on a dial of any size. The clicks on
+    /// 0 along the way are not the same.
+    pub fn compose(rotations: &[Rotate]) -> Result<Self> {
+        let net = rotations
+            .iter()
+            .map(|r| r.direction as i128 * r.steps as i128)
+            .sum::<i128>();
+        Ok(Self {
+            direction: if net < 0 { -1 } else { 1 },
+            steps: net.unsigned_abs().try_into()?,
+        })
+    }
+}
+
+/// The rotations undoing `rotations`: their inverses, last one first.
+pub fn reversed(rotations: &[Rotate]) -> Vec<Rotate> {
+    rotations.iter().rev().map(Rotate::inverse).collect()
+}
+
+impl FromStr for Rotate {
+    type Err = ParseError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let direction = match chars.next() {
+            Some('L') => -1,
+            Some('R') => 1,
+            other => {
+                let text = other.map(String::from).unwrap_or_default();
+                return Err(ParseError::new(1, 1, &text, "L or R"));
+            }
+        };
+        let steps = number(chars.as_str(), 1, 2)?;
+        Result::Ok(Self { direction, steps })
+    }
+}
+
+impl Display for Rotate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = if self.direction < 0 { 'L' } else { 'R' };
+        write!(f, "{}{}", direction, self.steps)
+    }
+}
+
+/// A dial numbered from 0 to `size - 1`, turned one click at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: usize,
+    position: usize,
+}
+
+/// What a dial went through during one rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub from: usize,
+    pub rotate: Rotate,
+    pub to: usize,
+    /// The dial points at 0 once the rotation is done.
+    pub landed: bool,
+    /// Clicks on 0 during the rotation, the last one excluded.
+    pub crossed: usize,
+}
+
+impl Turn {
+    /// Every click on 0, the last one included.
+    pub fn zero_hits(&self) -> usize {
+        self.crossed + usize::from(self.landed && self.rotate.steps > 0)
+    }
+}
+
+/// Totals of a sequence of turns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DialStats {
+    /// Rotations ending on 0.
+    pub landed: usize,
+    /// Clicks on 0 during the rotations, the last click of each one excluded.
+    pub crossed: usize,
+    /// Every click on 0.
+    pub zero_hits: usize,
+}
+
+impl DialStats {
+    fn add(&mut self, turn: &Turn) {
+        self.landed += usize::from(turn.landed);
+        self.crossed += turn.crossed;
+        self.zero_hits += turn.zero_hits();
+    }
+}
+
+impl Dial {
+    pub fn new(size: usize, start: usize) -> Result<Self> {
+        if start >= size {
+            return Err(anyhow!(
+                "Invalid start {} for a dial of {} positions",
+                start,
+                size
+            ));
+        }
+        Ok(Self {
+            size,
+            position: start,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Applies `rotate` without simulating each click.
+    ///
+    /// Unrolling the dial onto the integers, a rotation visits `p+1..=p+s` to the right or
+    /// `p-s..=p-1` to the left, and the clicks on 0 are the multiples of the size in that
+    /// interval: a difference of two floor divisions.
+    pub fn turn(&mut self, rotate: &Rotate) -> Turn {
+        let (size, from) = (self.size as i128, self.position as i128);
+        let steps = rotate.steps as i128;
+        let (to, hits) = if rotate.direction < 0 {
+            let to = from - steps;
+            (to, (from - 1).div_euclid(size) - (to - 1).div_euclid(size))
+        } else {
+            let to = from + steps;
+            (to, to.div_euclid(size) - from.div_euclid(size))
+        };
+        self.position = to.rem_euclid(size) as usize;
+        let landed = self.position == 0;
+        Turn {
+            from: from as usize,
+            rotate: *rotate,
+            to: self.position,
+            landed,
+            crossed: hits as usize - usize::from(landed && steps > 0),
+        }
+    }
+
+    /// Applies every rotation in order.
+    pub fn run(&mut self, rotations: &[Rotate]) -> DialStats {
+        let mut stats = DialStats::default();
+        for rotate in rotations {
+            stats.add(&self.turn(rotate));
+        }
+        stats
+    }
+
+    /// Runs `rotations` backwards, bringing the dial back to where it was before [`run`].
+    ///
+    /// [`run`]: Dial::run
+    pub fn undo(&mut self, rotations: &[Rotate]) -> DialStats {
+        self.run(&reversed(rotations))
+    }
+}
+
+/// Parses `SIZE:START`, e.g. `100:50`.
+impl FromStr for Dial {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let [size, start] = crate::parse::tuple(s, ':', 1)?;
+        Dial::new(size, start)
+    }
+}
+
+/// Turns every dial with each rotation in turn, and returns the totals of each dial.
+pub fn run_dials(dials: &mut [Dial], rotations: &[Rotate]) -> Vec<DialStats> {
+    let mut stats = vec![DialStats::default(); dials.len()];
+    for rotate in rotations {
+        for (dial, stats) in dials.iter_mut().zip(&mut stats) {
+            stats.add(&dial.turn(rotate));
+        }
+    }
+    stats
+}
+
+/// The dial of the safe: 100 positions, starting at 50.
+fn safe_dial() -> Dial {
+    Dial {
+        size: 100,
+        position: 50,
+    }
+}
+
+/// The turns of the safe dial, one per rotation, stopping after rotation `until` if given.
+/// Rotations are numbered from 1, like the lines of the input.
+pub fn trace(rotations: &[Rotate], until: Option<usize>) -> Vec<Turn> {
+    let mut dial = safe_dial();
+    let count = until.map_or(rotations.len(), |until| until.min(rotations.len()));
+    rotations[..count].iter().map(|r| dial.turn(r)).collect()
+}
+
+/// How [`write_trace`] prints the turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum TraceFormat {
+    /// Aligned table followed by the totals.
+    #[default]
+    Table,
+    /// One JSON object per turn.
+    Json,
+}
+
+/// A turn flattened for JSON.
+#[derive(Debug, Serialize)]
+struct TraceRow {
+    index: usize,
+    from: usize,
+    rotation: String,
+    to: usize,
+    landed: bool,
+    crossed: usize,
+    zero_hits: usize,
+}
+
+/// Writes a trace as an aligned table followed by the totals, or as one JSON object per
+/// turn.
+pub fn write_trace<W: Write>(out: &mut W, turns: &[Turn], format: TraceFormat) -> Result<()> {
+    let rows = turns.iter().enumerate().map(|(i, turn)| TraceRow {
+        index: i + 1,
+        from: turn.from,
+        rotation: turn.rotate.to_string(),
+        to: turn.to,
+        landed: turn.landed,
+        crossed: turn.crossed,
+        zero_hits: turn.zero_hits(),
+    });
+    match format {
+        TraceFormat::Table => {
+            writeln!(out, "    #  From  Rotation    To  Landed  Crossed  Hits")?;
+            let mut stats = DialStats::default();
+            for (row, turn) in rows.zip(turns) {
+                writeln!(
+                    out,
+                    "{:>5}  {:>4}  {:<8}  {:>4}  {:<6}  {:>7}  {:>4}",
+                    row.index,
+                    row.from,
+                    row.rotation,
+                    row.to,
+                    if row.landed { "yes" } else { "" },
+                    row.crossed,
+                    row.zero_hits
+                )?;
+                stats.add(turn);
+            }
+            writeln!(
+                out,
+                "Landed on 0: {}, crossed 0: {}, zero hits: {}",
+                stats.landed, stats.crossed, stats.zero_hits
+            )?;
+        }
+        TraceFormat::Json => {
+            for row in rows {
+                serde_json::to_writer(&mut *out, &row)?;
+                writeln!(out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: &'static str = "01";
+
+    type Input = Vec<Rotate>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Rotate::list(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(safe_dial().run(input).landed)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(safe_dial().run(input).zero_hits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    crate::example_tests! {
+        Day01,
+        part1_example: part1(TEST) == 3,
+        part2_example: part2(TEST) == 6,
+    }
+
+    #[test]
+    fn parse_reports_bad_rotations() {
+        let error = |input: &str| Day01::parse(input.as_bytes()).unwrap_err().to_string();
+        assert_eq!(
+            "line 2, column 1: expected L or R, found `X`",
+            error("L68\nX30\n")
+        );
+        assert_eq!(
+            "line 1, column 2: expected a number, found `6a`",
+            error("L6a\n")
+        );
+        assert_eq!(
+            "line 2, column 1: expected L or R, found nothing",
+            error("L68\n\nR30\n")
+        );
+    }
+
+    /// Turns the dial one click at a time.
+    fn simulate(dial: &mut Dial, rotate: &Rotate) -> Turn {
+        let from = dial.position;
+        let mut clicks_on_zero = Vec::new();
+        for _ in 0..rotate.steps {
+            let position = dial.position as i64 + rotate.direction as i64;
+            dial.position = position.rem_euclid(dial.size as i64) as usize;
+            clicks_on_zero.push(dial.position == 0);
+        }
+        clicks_on_zero.pop();
+        Turn {
+            from,
+            rotate: *rotate,
+            to: dial.position,
+            landed: dial.position == 0,
+            crossed: clicks_on_zero.iter().filter(|&&zero| zero).count(),
+        }
+    }
+
+    #[test]
+    fn turns_count_landing_and_crossing_apart() -> Result<()> {
+        let mut dial = Dial::new(100, 50)?;
+        let turn = dial.turn(&Rotate::from_str("R250")?);
+        assert_eq!(
+            (0, true, 2, 3),
+            (turn.to, turn.landed, turn.crossed, turn.zero_hits())
+        );
+        let turn = dial.turn(&Rotate::from_str("L0")?);
+        assert_eq!((true, 0, 0), (turn.landed, turn.crossed, turn.zero_hits()));
+        let turn = dial.turn(&Rotate::from_str("L1")?);
+        assert_eq!((99, false, 0), (turn.to, turn.landed, turn.crossed));
+        assert!(Dial::new(10, 10).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn trace_stops_at_the_given_rotation() -> Result<()> {
+        let rotations = Day01::parse(TEST.as_bytes())?;
+        let turns = trace(&rotations, Some(3));
+        assert_eq!(3, turns.len());
+        assert_eq!((50, 82), (turns[0].from, turns[0].to));
+        assert_eq!(10, trace(&rotations, None).len());
+        assert_eq!(10, trace(&rotations, Some(99)).len());
+
+        let mut out = Vec::new();
+        write_trace(&mut out, &turns, TraceFormat::Table)?;
+        let table = String::from_utf8(out)?;
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(
+            "    1    50  L68         82                1     1",
+            lines[1]
+        );
+        assert_eq!(
+            "    3    52  R48          0  yes           0     1",
+            lines[3]
+        );
+        assert_eq!("Landed on 0: 1, crossed 0: 1, zero hits: 2", lines[4]);
+
+        let mut out = Vec::new();
+        write_trace(&mut out, &turns[2..], TraceFormat::Json)?;
+        let row: serde_json::Value = serde_json::from_slice(&out)?;
+        assert_eq!("R48", row["rotation"]);
+        assert_eq!(true, row["landed"]);
+        Ok(())
+    }
+
+    #[test]
+    fn dials_of_several_sizes_share_the_rotations() -> Result<()> {
+        let rotations = Day01::parse(TEST.as_bytes())?;
+        let mut dials = vec![safe_dial(), Dial::from_str("10:0")?, Dial::new(7, 3)?];
+        let stats = run_dials(&mut dials, &rotations);
+        assert_eq!(3, stats[0].landed);
+        assert_eq!(6, stats[0].zero_hits);
+        for (i, start) in [safe_dial(), Dial::new(10, 0)?, Dial::new(7, 3)?]
+            .into_iter()
+            .enumerate()
+        {
+            let mut alone = start;
+            assert_eq!(alone.run(&rotations), stats[i]);
+            assert_eq!(alone, dials[i]);
+            dials[i].undo(&rotations);
+            assert_eq!(start, dials[i]);
+        }
+        assert!(Dial::from_str("10").is_err());
+        assert!(Dial::from_str("10:10").is_err());
+
+        let net = Rotate::compose(&rotations)?;
+        assert_eq!("L218", net.to_string());
+        assert_eq!(Rotate::from_str("R218")?, net.inverse());
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn turn_matches_a_click_by_click_simulation(
+            size in 1usize..20,
+            start in 0usize..20,
+            rotations in prop::collection::vec((prop::bool::ANY, 0usize..70), 0..20),
+        ) {
+            let mut dial = Dial::new(size, start % size).unwrap();
+            let mut simulated = dial;
+            for (left, steps) in rotations {
+                let rotate = Rotate { direction: if left { -1 } else { 1 }, steps };
+                prop_assert_eq!(simulate(&mut simulated, &rotate), dial.turn(&rotate));
+            }
+        }
+
+        #[test]
+        fn undo_and_compose_preserve_positions(
+            size in 1usize..20,
+            start in 0usize..20,
+            rotations in prop::collection::vec((prop::bool::ANY, 0usize..70), 0..20),
+        ) {
+            let rotations = rotations
+                .into_iter()
+                .map(|(left, steps)| Rotate { direction: if left { -1 } else { 1 }, steps })
+                .collect::<Vec<_>>();
+            let start = Dial::new(size, start % size).unwrap();
+            let mut dial = start;
+            dial.run(&rotations);
+            let mut composed = start;
+            composed.turn(&Rotate::compose(&rotations).unwrap());
+            prop_assert_eq!(dial, composed);
+            dial.undo(&rotations);
+            prop_assert_eq!(start, dial);
+        }
+    }
+}
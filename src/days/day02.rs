@@ -0,0 +1,165 @@
+use crate::Solution;
+use crate::digits::{Base, Pattern};
+use crate::parse::{range, tokens};
+use anyhow::*;
+use std::io::BufRead;
+
+pub const TEST: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+#[derive(Debug, Clone)]
+pub struct Range {
+    left: usize,
+    right: usize,
+}
+
+impl Range {
+    /// The comma-separated ranges of the input, parsed one at a time as they are read.
+    pub fn stream<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Self>> {
+        tokens(reader, b',').map(|token| {
+            let token = token?;
+            let range = range(&token.text, token.line, token.column)?;
+            Ok(Self {
+                left: *range.start(),
+                right: *range.end(),
+            })
+        })
+    }
+
+    fn read_all(reader: impl BufRead) -> Result<Vec<Self>> {
+        Self::stream(reader).collect()
+    }
+
+    /// Numbers made of one block of digits repeated twice, like `123123`.
+    fn find_as_twice_sequence(&self) -> impl Iterator<Item = usize> {
+        Pattern::Copies(2).numbers(self.left..=self.right, Base::DECIMAL)
+    }
+
+    /// Numbers made of one block of digits repeated any number of times, like `121212`.
+    fn find_as_any_sequence(&self) -> impl Iterator<Item = usize> {
+        Pattern::RepeatedAtLeast(2).numbers(self.left..=self.right, Base::DECIMAL)
+    }
+}
+
+/// Adds `ids` to `total`, failing instead of overflowing: huge ranges hold huge ids.
+fn add_ids(total: usize, ids: impl IntoIterator<Item = usize>) -> Result<usize> {
+    ids.into_iter().try_fold(total, |total, id| {
+        total
+            .checked_add(id)
+            .ok_or_else(|| anyhow!("The sum of invalid ids overflows"))
+    })
+}
+
+/// Both answers, reading the input range by range instead of parsing it whole first, and
+/// summing the ids of each range as they are generated: the memory used depends neither on
+/// the size of the input nor on the number of invalid ids.
+pub fn stream_sums<R: BufRead>(reader: R) -> Result<(usize, usize)> {
+    let (mut twice, mut any) = (0usize, 0usize);
+    for range in Range::stream(reader) {
+        let range = range?;
+        twice = add_ids(twice, range.find_as_twice_sequence())?;
+        any = add_ids(any, range.find_as_any_sequence())?;
+    }
+    Ok((twice, any))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: &'static str = "02";
+
+    type Input = Vec<Range>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Range::read_all(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        input
+            .iter()
+            .try_fold(0, |total, r| add_ids(total, r.find_as_twice_sequence()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        input
+            .iter()
+            .try_fold(0, |total, r| add_ids(total, r.find_as_any_sequence()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufReader, Read};
+
+    crate::example_tests! {
+        Day02,
+        part1_example: part1(TEST) == 1227775554,
+        part2_example: part2(TEST) == 4174379265,
+    }
+
+    /// `pattern` repeated `count` times, produced as it is read.
+    struct Repeat {
+        pattern: &'static [u8],
+        position: usize,
+        count: usize,
+    }
+
+    impl Read for Repeat {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.count == 0 {
+                return std::result::Result::Ok(0);
+            }
+            let rest = &self.pattern[self.position..];
+            let n = rest.len().min(buf.len());
+            buf[..n].copy_from_slice(&rest[..n]);
+            self.position += n;
+            if self.position == self.pattern.len() {
+                self.position = 0;
+                self.count -= 1;
+            }
+            std::result::Result::Ok(n)
+        }
+    }
+
+    #[test]
+    fn stream_sums_a_generated_input() -> Result<()> {
+        assert_eq!((1227775554, 4174379265), stream_sums(TEST.as_bytes())?);
+        let generated = Repeat {
+            pattern: b"11-22,95-115,",
+            position: 0,
+            count: 100_000,
+        };
+        let reader = BufReader::new(generated.chain("998-1012\n".as_bytes()));
+        assert_eq!(
+            (100_000 * (33 + 99) + 1010, 100_000 * (33 + 210) + 2009),
+            stream_sums(reader)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn overflowing_sums_are_errors() -> Result<()> {
+        let input = "18000000001800000000-18000000011800000001";
+        let parsed = Day02::parse(input.as_bytes())?;
+        let error = "The sum of invalid ids overflows";
+        assert_eq!(error, Day02::part1(&parsed).unwrap_err().to_string());
+        assert_eq!(error, Day02::part2(&parsed).unwrap_err().to_string());
+        assert!(stream_sums(input.as_bytes()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_reports_bad_ranges() {
+        let error = |input: &str| Day02::parse(input.as_bytes()).unwrap_err().to_string();
+        assert_eq!(
+            "line 1, column 7: expected a range like `a-b`, found `95`",
+            error("11-22,95")
+        );
+        assert_eq!(
+            "line 1, column 10: expected a number, found `1x5`",
+            error("11-22,95-1x5")
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::Solution;
+use crate::parse::{ParseError, lines};
+use anyhow::*;
+use std::io::BufRead;
+
+pub const TEST: &str = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+struct Battery {
+    power_level: usize,
+}
+
+impl Battery {
+    fn find_power_level(chain: &[usize], nb_battery: usize) -> Result<usize> {
+        let len = chain.len();
+        if len < nb_battery {
+            return Err(anyhow!(
+                "A bank of {} batteries cannot turn {} on",
+                len,
+                nb_battery
+            ));
+        }
+        let mut result = vec![0; nb_battery];
+        for (i, &sub_chain) in chain.iter().enumerate() {
+            let from = i.saturating_sub(len - nb_battery);
+            for j in from..nb_battery {
+                if sub_chain > result[j] {
+                    result[j] = sub_chain;
+                    result[j + 1..].fill(0);
+                    break;
+                }
+            }
+        }
+        Ok(result.iter().fold(0, |acc, v| acc * 10 + v))
+    }
+
+    fn new(chain: &[usize], nb_battery: usize) -> Result<Self> {
+        let power_level = Self::find_power_level(chain, nb_battery)?;
+        Ok(Self { power_level })
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: &'static str = "03";
+
+    /// Joltage digits of each bank.
+    type Input = Vec<Vec<usize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut banks = Vec::new();
+        for (i, line) in lines(reader)?.into_iter().enumerate() {
+            let bank = line
+                .chars()
+                .enumerate()
+                .map(|(j, c)| {
+                    c.to_digit(10)
+                        .map(|d| d as usize)
+                        .ok_or_else(|| ParseError::new(i + 1, j + 1, &c.to_string(), "a digit"))
+                })
+                .collect::<Result<_, _>>()?;
+            banks.push(bank);
+        }
+        Ok(banks)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        input
+            .iter()
+            .map(|b| Ok(Battery::new(b, 2)?.power_level))
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        input
+            .iter()
+            .map(|b| Ok(Battery::new(b, 12)?.power_level))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day03,
+        part1_example: part1(TEST) == 357,
+        part2_example: part2(TEST) == 3121910778619,
+    }
+
+    #[test]
+    fn parse_reports_bad_digits() {
+        let error = Day03::parse("987\n81x\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            "line 2, column 3: expected a digit, found `x`",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::Solution;
+use crate::automaton::{Edges, Erosion, Neighborhood};
+use crate::grid::{Grid, Pos};
+use crate::visual::Recording;
+use anyhow::*;
+use std::io::BufRead;
+
+pub const TEST: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+/// The map of the paper rolls (`@`).
+#[derive(Debug, Clone)]
+pub struct Rolls {
+    grid: Grid<bool>,
+}
+
+impl Rolls {
+    fn new<R: BufRead>(reader: R) -> Result<Self> {
+        let grid = Grid::parse(reader, "@ or .", |c| match c {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        Ok(Self { grid })
+    }
+
+    /// Rolls a forklift can reach under `rule`.
+    pub fn forklift(&self, rule: &Erosion) -> Vec<Pos> {
+        rule.eroded(&self.grid)
+    }
+
+    /// The wave in which each roll is removed when forklifts keep taking every reachable
+    /// roll: `Some(0)` for the rolls reachable from the start, `Some(1)` for those freed by
+    /// their removal, and so on. `None` for empty cells and rolls that are never reached.
+    pub fn removal_waves(&self, rule: &Erosion) -> Grid<Option<usize>> {
+        rule.waves(&self.grid)
+    }
+
+    /// The map before each removal wave, with the rolls taken in that wave drawn as `x`,
+    /// then the map once no forklift can reach anything.
+    pub fn record_removal(&self, rule: &Erosion) -> Recording {
+        let waves = self.removal_waves(rule);
+        let last = waves.iter().filter_map(|(_, w)| *w).max();
+        let mut cells = self.grid.map(|&roll| (roll, None));
+        for (pos, &wave) in waves.iter() {
+            cells[pos].1 = wave;
+        }
+        let mut recording = Recording::new();
+        for frame in 0..=last.map_or(0, |w| w + 1) {
+            recording.record(&cells, |&(roll, wave)| match wave {
+                _ if !roll => '.',
+                Some(w) if w < frame => '.',
+                Some(w) if w == frame => 'x',
+                _ => '@',
+            });
+        }
+        recording
+    }
+}
+
+/// A forklift reaches rolls with fewer than 4 rolls among the 8 cells around them.
+pub const FORKLIFT: Erosion = Erosion::new(Neighborhood::Moore, Edges::Bounded, 4);
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: &'static str = "04";
+
+    type Input = Rolls;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Rolls::new(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(input.forklift(&FORKLIFT).len())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let waves = input.removal_waves(&FORKLIFT);
+        Ok(waves.iter().filter(|(_, wave)| wave.is_some()).count())
+    }
+
+    fn visualize(input: &Self::Input) -> Option<Recording> {
+        Some(input.record_removal(&FORKLIFT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day04,
+        part1_example: part1(TEST) == 13,
+        part2_example: part2(TEST) == 43,
+    }
+
+    #[test]
+    fn removal_waves() -> Result<()> {
+        let rolls = Day04::parse(TEST.as_bytes())?;
+        let waves = rolls.removal_waves(&FORKLIFT);
+        assert_eq!(Some(&Some(0)), waves.get((0, 2)));
+        assert_eq!(Some(&None), waves.get((0, 0)));
+        let last = waves.iter().filter_map(|(_, w)| *w).max();
+        assert_eq!(Some(8), last);
+
+        let orthogonal = Erosion::new(Neighborhood::VonNeumann, Edges::Bounded, 2);
+        assert_eq!(11, rolls.forklift(&orthogonal).len());
+        Ok(())
+    }
+
+    #[test]
+    fn removal_is_recorded_wave_by_wave() -> Result<()> {
+        let rolls = Day04::parse(TEST.as_bytes())?;
+        let recording = Day04::visualize(&rolls).unwrap();
+        let frames = recording.frames();
+        assert_eq!(10, frames.len());
+        let show = |i: usize| frames[i].display(|&c| c).to_string();
+        assert!(show(0).starts_with("..xx.xx@x.\nx@@.@.@.@@\n"));
+        assert!(show(1).starts_with(".......x..\n.@@.x.x.@x\n"));
+        let removed = |i: usize| frames[i].iter().filter(|&(_, &c)| c == 'x').count();
+        assert_eq!(43, (0..10).map(removed).sum::<usize>());
+        assert_eq!(0, removed(9));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_reports_bad_cells_and_rows() {
+        let error = |input: &str| Day04::parse(input.as_bytes()).unwrap_err().to_string();
+        assert_eq!(
+            "line 2, column 2: expected @ or ., found `#`",
+            error("@.\n.#\n")
+        );
+        assert_eq!(
+            "line 2, column 1: expected a row of 2 cells, found `@`",
+            error("@.\n@\n")
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::Solution;
+use crate::interval::IntervalSet;
+use crate::parse::{ParseError, number, range, sections};
+use anyhow::*;
+use std::io::BufRead;
+
+pub const TEST: &str = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+#[derive(Debug)]
+pub struct Fridge {
+    fresh: IntervalSet<usize>,
+    available: Vec<usize>,
+}
+
+impl Fridge {
+    fn new<R: BufRead>(reader: R) -> Result<Self> {
+        let mut ranges = Vec::new();
+        let mut available: Vec<usize> = Vec::new();
+        let mut sections = sections(reader)?.into_iter();
+        for (line, text) in sections.next().iter().flat_map(|s| s.numbered()) {
+            ranges.push(range(text, line, 1)?);
+        }
+        for (line, text) in sections.next().iter().flat_map(|s| s.numbered()) {
+            available.push(number(text, line, 1)?);
+        }
+        if let Some(extra) = sections.next() {
+            let error = ParseError::new(
+                extra.first_line,
+                1,
+                &extra.lines[0],
+                "the end of the input after the available ids",
+            );
+            return Err(error.into());
+        }
+        // Sorted then merged at once: inserting the ranges one by one is quadratic.
+        let fresh = ranges.into_iter().collect::<IntervalSet<_>>();
+        Ok(Self { fresh, available })
+    }
+
+    fn fresh_available(&self) -> Vec<usize> {
+        let fresh = self.fresh.contains_each(&self.available);
+        self.available
+            .iter()
+            .zip(fresh)
+            .filter_map(|(&id, fresh)| fresh.then_some(id))
+            .collect()
+    }
+
+    fn really_fresh(&self) -> Result<usize> {
+        Ok(self.fresh.len().try_into()?)
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: &'static str = "05";
+
+    type Input = Fridge;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Fridge::new(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(input.fresh_available().len())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        input.really_fresh()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day05,
+        part1_example: part1(TEST) == 3,
+        part2_example: part2(TEST) == 14,
+    }
+
+    #[test]
+    fn parse_reports_bad_ranges_and_ids() {
+        let error = |input: &str| Day05::parse(input.as_bytes()).unwrap_err().to_string();
+        assert_eq!(
+            "line 2, column 1: expected a range like `a-b`, found `10`",
+            error("3-5\n10\n")
+        );
+        assert_eq!(
+            "line 1, column 3: expected a number, found `x`",
+            error("3-x\n")
+        );
+        assert_eq!(
+            "line 3, column 1: expected a number, found `1.5`",
+            error("3-5\n\n1.5\n")
+        );
+        assert_eq!(
+            "line 5, column 1: expected the end of the input after the available ids, found `2`",
+            error("3-5\n\n1\n\n2\n")
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use crate::Solution;
+use crate::parse::{ParseError, columns, lines};
+use anyhow::*;
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub const TEST: &str = "123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   + ";
+
+#[derive(Debug, Clone)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '*' => Some(Operator::Multiply),
+            '+' => Some(Operator::Add),
+            _ => None,
+        }
+    }
+
+    fn initial_value(&self) -> usize {
+        match self {
+            Operator::Add => 0,
+            Operator::Multiply => 1,
+        }
+    }
+
+    fn apply(&self, a: usize, b: usize) -> usize {
+        match self {
+            Operator::Add => a + b,
+            Operator::Multiply => a * b,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Operation {
+    values: Vec<String>,
+    operator: Operator,
+}
+
+impl Operation {
+    fn parse<R: BufRead>(reader: R) -> Result<Vec<Self>> {
+        let lines = lines(reader)?;
+        let Some((_, numbers)) = lines.split_last() else {
+            return Ok(Vec::new());
+        };
+        for (i, line) in numbers.iter().enumerate() {
+            if let Some((j, c)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !c.is_ascii_digit() && *c != ' ')
+            {
+                let error = ParseError::new(i + 1, j + 1, &c.to_string(), "a digit or a space");
+                return Err(error.into());
+            }
+        }
+        let operator_line = lines.len();
+        columns(&lines)
+            .into_iter()
+            .map(|mut column| {
+                let cell = column.cells.pop().unwrap_or_default();
+                let symbol = cell.trim();
+                let mut chars = symbol.chars();
+                let Some(operator) = chars.next().and_then(Operator::from_char) else {
+                    let offset = cell.find(|c| c != ' ').unwrap_or(0);
+                    let column = column.column + offset;
+                    return Err(ParseError::new(operator_line, column, symbol, "* or +").into());
+                };
+                if chars.next().is_some() {
+                    let error = ParseError::new(operator_line, column.column, symbol, "* or +");
+                    return Err(error.into());
+                }
+                Ok(Operation {
+                    values: column.cells,
+                    operator,
+                })
+            })
+            .collect()
+    }
+
+    fn internal_compute(values: &[String], operator: &Operator) -> usize {
+        values.iter().fold(operator.initial_value(), |acc, value| {
+            let value = usize::from_str(value.trim()).unwrap_or_default();
+            operator.apply(acc, value)
+        })
+    }
+    fn compute(&self) -> usize {
+        Self::internal_compute(&self.values, &self.operator)
+    }
+
+    fn compute_right_to_left(&self) -> usize {
+        let mut values = vec![];
+        for i in 0..self.values.first().map_or(0, String::len) {
+            let mut current = String::new();
+            for value in self.values.iter() {
+                current = format!("{}{}", current, &value[i..i + 1]);
+            }
+            values.push(current)
+        }
+        Self::internal_compute(&values, &self.operator)
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: &'static str = "06";
+
+    type Input = Vec<Operation>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Operation::parse(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(input.iter().map(Operation::compute).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(input.iter().map(Operation::compute_right_to_left).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day06,
+        part1_example: part1(TEST) == 4277556,
+        part2_example: part2(TEST) == 3263827,
+    }
+
+    #[test]
+    fn parse_reports_bad_numbers_and_operators() {
+        let error = |input: &str| Day06::parse(input.as_bytes()).unwrap_err().to_string();
+        assert_eq!(
+            "line 2, column 3: expected * or +, found `-`",
+            error("1 2\n* -\n")
+        );
+        assert_eq!(
+            "line 1, column 3: expected a digit or a space, found `x`",
+            error("1 x\n* +\n")
+        );
+        assert_eq!(
+            "line 2, column 1: expected * or +, found nothing",
+            error("1 2\n  +\n\n")
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use crate::Solution;
+use crate::grid::Grid;
+use crate::visual::Recording;
+use anyhow::*;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::BufRead;
+
+pub const TEST: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+#[derive(Debug, Clone, PartialEq)]
+enum Item {
+    Start,
+    Splitter,
+    Beam,
+    None,
+}
+
+impl Item {
+    fn to_char(&self) -> char {
+        match self {
+            Item::Start => 'S',
+            Item::Splitter => '^',
+            Item::Beam => '|',
+            Item::None => '.',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Beam {
+    grid: Grid<Item>,
+    nb_splitting: usize,
+}
+
+impl Display for Beam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid.display(Item::to_char))
+    }
+}
+
+impl Beam {
+    fn init<R: BufRead>(reader: R) -> Result<Self> {
+        let grid = Grid::parse(reader, "S, ^ or .", |c| match c {
+            'S' => Some(Item::Start),
+            '^' => Some(Item::Splitter),
+            '.' => Some(Item::None),
+            _ => None,
+        })?;
+        Ok(Self {
+            grid,
+            nb_splitting: 0,
+        })
+    }
+
+    fn next(&self, row: usize) -> Self {
+        let prev = self.grid.row(row - 1);
+        let mut current_row = self.grid.row(row).to_vec();
+        let mut nb_splitting = self.nb_splitting;
+        for (idx, c) in self.grid.row(row).iter().enumerate() {
+            let is_none = matches!(c, Item::None);
+            let prev_row_needs_beam = matches!(prev.get(idx), Some(Item::Beam) | Some(Item::Start));
+            if is_none && prev_row_needs_beam {
+                current_row[idx] = Item::Beam;
+            } else if matches!(c, Item::Splitter) && prev_row_needs_beam {
+                let mut splitting = 0;
+                if idx > 0 && matches!(current_row[idx - 1], Item::None) {
+                    splitting += 1;
+                    current_row[idx - 1] = Item::Beam;
+                }
+                if idx < current_row.len() - 1 && matches!(current_row[idx + 1], Item::None) {
+                    splitting += 1;
+                    current_row[idx + 1] = Item::Beam;
+                }
+                if splitting > 0 {
+                    nb_splitting += 1;
+                }
+            }
+        }
+        let mut rows = self.clone();
+        rows.grid.row_mut(row).clone_from_slice(&current_row);
+        rows.nb_splitting = nb_splitting;
+        rows
+    }
+
+    fn count_paths_from_start(
+        &self,
+        row: usize,
+        col: usize,
+        mut cache: HashMap<(usize, usize), usize>,
+    ) -> (usize, HashMap<(usize, usize), usize>) {
+        if cache.contains_key(&(row, col)) {
+            return (cache[&(row, col)], cache);
+        }
+        let value = &self.grid[(row, col)];
+        let is_beam = value == &Item::Beam || value == &Item::Start;
+        let is_splitter = value == &Item::Splitter;
+        if !is_beam && !is_splitter {
+            cache.insert((row, col), 0);
+            return (0, cache);
+        }
+        if row == self.grid.height() - 1 {
+            let value = if is_beam { 1 } else { 0 };
+            cache.insert((row, col), value);
+            return (value, cache);
+        }
+        let (value, mut cache) = if is_splitter {
+            let (left, cache) = if col >= 1 {
+                self.count_paths_from_start(row + 1, col - 1, cache)
+            } else {
+                (0, cache)
+            };
+            let (right, cache) = if col + 1 < self.grid.width() {
+                self.count_paths_from_start(row + 1, col + 1, cache)
+            } else {
+                (0, cache)
+            };
+            (left + right, cache)
+        } else {
+            self.count_paths_from_start(row + 1, col, cache)
+        };
+        cache.insert((row, col), value);
+        (value, cache)
+    }
+
+    fn final_state(&self) -> Self {
+        let mut result = self.clone();
+        for i in 1..self.grid.height() {
+            let next = result.next(i);
+            result = next;
+        }
+        result
+    }
+
+    /// The manifold as the beam goes down, one row per frame.
+    fn record_propagation(&self) -> Recording {
+        let mut recording = Recording::new();
+        let mut state = self.clone();
+        recording.record(&state.grid, Item::to_char);
+        for i in 1..self.grid.height() {
+            state = state.next(i);
+            recording.record(&state.grid, Item::to_char);
+        }
+        recording
+    }
+
+    fn final_quantum_states(&self) -> usize {
+        let final_state = self.final_state();
+        let mut result = 0;
+        let mut cache = HashMap::new();
+        for i in 0..final_state.grid.width() {
+            let (value, c_cache) = final_state.count_paths_from_start(0, i, cache);
+            result += value;
+            cache = c_cache;
+        }
+        result
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: &'static str = "07";
+
+    type Input = Beam;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Beam::init(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(input.final_state().nb_splitting)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(input.final_quantum_states())
+    }
+
+    fn visualize(input: &Self::Input) -> Option<Recording> {
+        Some(input.record_propagation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day07,
+        part1_example: part1(TEST) == 21,
+        part2_example: part2(TEST) == 40,
+    }
+
+    #[test]
+    fn propagation_is_recorded_row_by_row() -> Result<()> {
+        let beam = Day07::parse(TEST.as_bytes())?;
+        let recording = Day07::visualize(&beam).unwrap();
+        let frames = recording.frames();
+        assert_eq!(16, frames.len());
+        assert_eq!(beam.to_string(), frames[0].display(|&c| c).to_string());
+        let last = beam.final_state().to_string();
+        assert_eq!(last, frames[15].display(|&c| c).to_string());
+        assert!(
+            frames[2]
+                .display(|&c| c)
+                .to_string()
+                .ends_with("...............\n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_reports_bad_cells_and_rows() {
+        let error = |input: &str| Day07::parse(input.as_bytes()).unwrap_err().to_string();
+        assert_eq!(
+            "line 2, column 3: expected S, ^ or ., found `|`",
+            error(".S.\n..|\n")
+        );
+        assert_eq!(
+            "line 2, column 1: expected a row of 3 cells, found `..`",
+            error(".S.\n..\n")
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::Solution;
+use crate::parse::tuples;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub const TEST: &str = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+type Coord = (usize, usize, usize);
+
+fn distance(a: &Coord, b: &Coord) -> f64 {
+    let square = |l: usize, r: usize| -> f64 { (l as f64 - r as f64).powf(2.0) };
+    let square_root = |v: f64| v.powf(0.5);
+    square_root(square(a.0, b.0) + square(a.1, b.1) + square(a.2, b.2))
+}
+
+#[derive(Debug)]
+struct Dsu {
+    parents: HashMap<Coord, Coord>,
+    ranks: HashMap<Coord, usize>,
+}
+
+impl Dsu {
+    fn new(items: Vec<Coord>) -> Self {
+        Self {
+            parents: items.iter().map(|c| (*c, *c)).collect(),
+            ranks: items.iter().map(|c| (*c, 1)).collect(),
+        }
+    }
+
+    fn find(&mut self, coord: Coord) -> Coord {
+        let parent_coord = *self.parents.get(&coord).unwrap_or(&coord);
+        if parent_coord == coord {
+            coord
+        } else {
+            let parent = self.find(self.parents[&coord]);
+            self.parents.insert(coord, parent);
+            parent
+        }
+    }
+
+    fn union(&mut self, coord1: Coord, coord2: Coord) -> bool {
+        let parent1 = self.find(coord1);
+        let parent2 = self.find(coord2);
+        if parent1 == parent2 {
+            return false;
+        }
+        let rank1 = self.ranks.remove(&parent1).unwrap_or(1);
+        let rank2 = self.ranks.remove(&parent2).unwrap_or(1);
+        if rank1 < rank2 {
+            self.parents.insert(parent1, parent2);
+            self.ranks.insert(parent2, rank1 + rank2);
+            self.ranks.insert(parent1, 0);
+        } else {
+            self.parents.insert(parent2, parent1);
+            self.ranks.insert(parent1, rank1 + rank2);
+            self.ranks.insert(parent2, 0);
+        }
+        true
+    }
+
+    fn ranks(&self) -> Vec<(Coord, usize)> {
+        self.ranks
+            .iter()
+            .filter(|&(_, v)| *v > 0)
+            .map(|(c, v)| (*c, *v))
+            .sorted_by(|(_, v1), (_, v2)| v1.cmp(v2))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Results {
+    boxes: Vec<Coord>,
+    /// Number of closest pairs to connect in part 1.
+    connections: usize,
+}
+
+impl Results {
+    fn new<R: BufRead>(reader: R) -> Result<Self> {
+        let boxes = tuples(reader, ',')?
+            .into_iter()
+            .map(|[x, y, z]| (x, y, z))
+            .collect();
+
+        Ok(Self {
+            boxes,
+            connections: 1000,
+        })
+    }
+
+    fn jonctions(&self) -> Vec<(&Coord, &Coord, f64)> {
+        let mut jonctions = Vec::new();
+        let size = self.boxes.len();
+        for (idx, l) in self.boxes.iter().enumerate() {
+            for r_i in idx + 1..size {
+                let r = &self.boxes[r_i];
+                if l == r {
+                    continue;
+                }
+                jonctions.push((l, r, distance(l, r)));
+            }
+        }
+        jonctions.sort_by(|(_, _, d1), (_, _, d2)| d1.total_cmp(d2));
+        jonctions
+    }
+
+    fn dsu(&self, limit: Option<usize>) -> (Dsu, (&Coord, &Coord)) {
+        let mut dsu = Dsu::new(self.boxes.to_vec());
+        let jonctions = self.jonctions();
+        let mut count = 0;
+        let limit = limit.unwrap_or(jonctions.len());
+        let mut coord_pairs = (jonctions[0].0, jonctions[0].1);
+        for &(l, r, _) in &jonctions {
+            coord_pairs = (l, r);
+            if count >= limit {
+                continue;
+            }
+            dsu.union(*l, *r);
+            let parent = dsu.find(*l);
+            if dsu.ranks[&parent] == dsu.ranks.len() {
+                break;
+            }
+            count += 1;
+        }
+
+        (dsu, coord_pairs)
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: &'static str = "08";
+
+    type Input = Results;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Results::new(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let (dsu, _) = input.dsu(Some(input.connections));
+        let ranks = dsu.ranks();
+        let result = ranks.iter().map(|c| c.1).sorted().rev().take(3).product();
+        Ok(result)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let (_, latest) = input.dsu(None);
+        Ok(latest.0.0 * latest.1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    crate::example_tests! {
+        Day08,
+        part2_example: part2(TEST) == 25272,
+    }
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let mut input = Day08::parse(BufReader::new(TEST.as_bytes()))?;
+        input.connections = 10;
+        assert_eq!(40, Day08::part1(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_reports_bad_coordinates() {
+        let error = |input: &str| Day08::parse(input.as_bytes()).unwrap_err().to_string();
+        assert_eq!(
+            "line 2, column 1: expected 3 numbers separated by `,`, found `1,2`",
+            error("1,2,3\n1,2\n")
+        );
+        assert_eq!(
+            "line 1, column 5: expected a number, found `z`",
+            error("1,2,z\n")
+        );
+    }
+}
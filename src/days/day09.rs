@@ -0,0 +1,146 @@
+use crate::Solution;
+use crate::parse::tuples;
+use anyhow::*;
+use std::cmp::{max, min};
+use std::io::BufRead;
+
+pub const TEST: &str = "7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+
+type Coord = (usize, usize);
+
+#[derive(Debug, Clone)]
+struct Rectangle {
+    x_min: usize,
+    x_max: usize,
+    y_min: usize,
+    y_max: usize,
+    pixels: i64,
+}
+
+impl Rectangle {
+    fn new(points: (Coord, Coord)) -> Self {
+        let x_min = min(points.0.0, points.1.0);
+        let x_max = max(points.0.0, points.1.0);
+        let y_min = min(points.0.1, points.1.1);
+        let y_max = max(points.0.1, points.1.1);
+        Self {
+            pixels: Self::compute_pixels(x_min, x_max, y_min, y_max),
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        }
+    }
+
+    fn compute_pixels(left: usize, right: usize, bottom: usize, top: usize) -> i64 {
+        ((right as i64 - left as i64).abs() + 1) * ((top as i64 - bottom as i64).abs() + 1)
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    points: Vec<Coord>,
+}
+
+impl Grid {
+    fn new<R: BufRead>(reader: R) -> Result<Self> {
+        let points = tuples(reader, ',')?
+            .into_iter()
+            .map(|[x, y]| (x, y))
+            .collect();
+
+        Ok(Self { points })
+    }
+
+    fn part_1(&self) -> Vec<Rectangle> {
+        let mut result = Vec::new();
+        for (i, p1) in self.points.iter().enumerate() {
+            for p2 in self.points.iter().skip(i + 1) {
+                result.push(Rectangle::new((*p1, *p2)));
+            }
+        }
+        result.sort_by(|r1, r2| r1.pixels.partial_cmp(&r2.pixels).unwrap());
+        result
+    }
+
+    fn part_2(&self) -> Vec<Rectangle> {
+        let rects = self.part_1();
+        let fold = |mut acc: Vec<Rectangle>, r: &Rectangle| {
+            let mut is_cut = false;
+            for (i, p1) in self.points.iter().enumerate() {
+                let p2 = &self.points[(i + 1) % self.points.len()];
+                let x3 = min(p1.0, p2.0);
+                let x4 = max(p1.0, p2.0);
+                let y3 = min(p1.1, p2.1);
+                let y4 = max(p1.1, p2.1);
+                if r.x_min < x4 && r.x_max > x3 && r.y_min < y4 && y3 < r.y_max {
+                    is_cut = true;
+                    break;
+                }
+            }
+            if !is_cut {
+                acc.push(r.clone());
+            }
+            acc
+        };
+
+        rects.iter().fold(Vec::new(), fold)
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: &'static str = "09";
+
+    type Input = Grid;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::new(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i64> {
+        let rectangles = input.part_1();
+        let largest_area = rectangles.last().unwrap().pixels;
+        Ok(largest_area)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i64> {
+        let rectangles = input.part_2();
+        let largest_area = rectangles.last().unwrap().pixels;
+        Ok(largest_area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day09,
+        part1_example: part1(TEST) == 50,
+        part2_example: part2(TEST) == 24,
+    }
+
+    #[test]
+    fn parse_reports_bad_coordinates() {
+        let error = |input: &str| Day09::parse(input.as_bytes()).unwrap_err().to_string();
+        assert_eq!(
+            "line 1, column 1: expected 2 numbers separated by `,`, found `7`",
+            error("7\n")
+        );
+        assert_eq!(
+            "line 2, column 4: expected a number, found `-1`",
+            error("7,1\n11,-1\n")
+        );
+    }
+}
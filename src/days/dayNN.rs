@@ -0,0 +1,41 @@
+use crate::Solution;
+use crate::parse::lines;
+use anyhow::*;
+use std::io::BufRead;
+
+pub const TEST: &str = "\
+<TEST-INPUT>
+";
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: &'static str = "NN";
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        lines(reader)
+    }
+
+    fn part1(_input: &Self::Input) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<usize> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        DayNN,
+        part1_example: part1(TEST) == 0,
+        part2_example: part2(TEST) == 0,
+    }
+}
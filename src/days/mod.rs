@@ -0,0 +1,31 @@
+use crate::runner::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+
+/// The module `scaffold` copies for a new day, compiled here so that it cannot rot.
+#[cfg(test)]
+#[path = "dayNN.rs"]
+mod template;
+
+/// Every solved day, in calendar order.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::of::<day01::Day01>(),
+        Day::of::<day02::Day02>(),
+        Day::of::<day03::Day03>(),
+        Day::of::<day04::Day04>(),
+        Day::of::<day05::Day05>(),
+        Day::of::<day06::Day06>(),
+        Day::of::<day07::Day07>(),
+        Day::of::<day08::Day08>(),
+        Day::of::<day09::Day09>(),
+    ]
+}
@@ -0,0 +1,322 @@
+//! Patterns in the digits of numbers written in any base from 2 to 36: repeated blocks and
+//! palindromes.
+
+use anyhow::*;
+use itertools::Itertools;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+/// A base from 2 to 36, the digits being `0-9` then `a-z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base(u32);
+
+impl Base {
+    pub const DECIMAL: Base = Base(10);
+
+    pub fn new(base: u32) -> Result<Self> {
+        if !(2..=36).contains(&base) {
+            return Err(anyhow!("Invalid base {}, expected 2 to 36", base));
+        }
+        Ok(Self(base))
+    }
+
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+/// How many digits `value` has in `base`.
+fn length(mut value: usize, base: Base) -> usize {
+    let mut length = 1;
+    while value >= base.0 as usize {
+        value /= base.0 as usize;
+        length += 1;
+    }
+    length
+}
+
+/// The digits of a number, most significant first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Digits {
+    base: Base,
+    digits: Vec<u8>,
+}
+
+impl Digits {
+    pub fn new(mut value: usize, base: Base) -> Self {
+        let mut digits = Vec::with_capacity(length(value, base));
+        loop {
+            digits.push((value % base.0 as usize) as u8);
+            value /= base.0 as usize;
+            if value == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        Self { base, digits }
+    }
+
+    pub fn base(&self) -> Base {
+        self.base
+    }
+
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+
+    pub fn len(&self) -> usize {
+        self.digits.len()
+    }
+
+    /// Always false: zero has one digit.
+    pub fn is_empty(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Length of the shortest block the digits are copies of: 2 for `1212`, 3 for `123123`,
+    /// the whole length when nothing repeats.
+    pub fn period(&self) -> usize {
+        // Longest proper prefix that is also a suffix, for each prefix (KMP failure function).
+        let mut border = vec![0; self.len()];
+        for i in 1..self.len() {
+            let mut k = border[i - 1];
+            while k > 0 && self.digits[i] != self.digits[k] {
+                k = border[k - 1];
+            }
+            if self.digits[i] == self.digits[k] {
+                k += 1;
+            }
+            border[i] = k;
+        }
+        let shift = self.len() - border.last().copied().unwrap_or(0);
+        if self.len().is_multiple_of(shift) {
+            shift
+        } else {
+            self.len()
+        }
+    }
+
+    /// How many copies of the shortest block make the digits: 3 for `121212`.
+    pub fn repetitions(&self) -> usize {
+        self.len() / self.period()
+    }
+
+    /// Whether the digits are exactly `count` copies of one block: `1111` is 2 copies of
+    /// `11` as well as 4 copies of `1`.
+    pub fn is_copies(&self, count: usize) -> bool {
+        count > 0 && self.repetitions().is_multiple_of(count)
+    }
+
+    pub fn is_palindrome(&self) -> bool {
+        self.digits.iter().eq(self.digits.iter().rev())
+    }
+}
+
+impl Display for Digits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = self
+            .digits
+            .iter()
+            .map(|&d| char::from_digit(d as u32, self.base.0).unwrap_or('?'))
+            .collect::<String>();
+        f.pad(&text)
+    }
+}
+
+/// A property of the digits of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pattern {
+    /// Exactly `n` copies of one block, `n` being 2 or more: `123123` for 2.
+    Copies(usize),
+    /// A block repeated `n` times or more, and at least twice: `123123` and `121212` for 2.
+    RepeatedAtLeast(usize),
+    /// The same digits read from either end.
+    Palindrome,
+}
+
+impl Pattern {
+    pub fn matches(&self, digits: &Digits) -> bool {
+        match *self {
+            Pattern::Copies(count) => count >= 2 && digits.is_copies(count),
+            Pattern::RepeatedAtLeast(count) => digits.repetitions() >= count.max(2),
+            Pattern::Palindrome => digits.is_palindrome(),
+        }
+    }
+
+    /// The numbers of `range` matching the pattern in `base`, in increasing order.
+    ///
+    /// Repeated blocks are generated directly, so the cost depends on the number of matches
+    /// rather than on the width of the range; palindromes are found by scanning. The numbers
+    /// are produced one at a time, so the memory used does not grow with their count.
+    pub fn numbers(
+        &self,
+        range: RangeInclusive<usize>,
+        base: Base,
+    ) -> Box<dyn Iterator<Item = usize>> {
+        let bounds = (*range.start(), *range.end());
+        let lengths = length(bounds.0, base)..=length(bounds.1, base);
+        match *self {
+            Pattern::Copies(count) => {
+                Box::new(lengths.flat_map(move |length| copies(bounds, base, count, length)))
+            }
+            Pattern::RepeatedAtLeast(count) => Box::new(lengths.flat_map(move |length| {
+                // `111111` is 2, 3 and 6 copies: merged in order, then kept once.
+                (count.max(2)..=length)
+                    .map(|count| copies(bounds, base, count, length))
+                    .kmerge()
+                    .dedup()
+            })),
+            Pattern::Palindrome => {
+                Box::new(range.filter(move |&value| Digits::new(value, base).is_palindrome()))
+            }
+        }
+    }
+
+    /// The [`numbers`](Self::numbers) of `range` matching the pattern, collected.
+    pub fn find(&self, range: RangeInclusive<usize>, base: Base) -> Vec<usize> {
+        self.numbers(range, base).collect()
+    }
+}
+
+/// Numbers of `length` digits between the inclusive `bounds` made of `count` copies of a
+/// block of digits, in increasing order.
+///
+/// With `L` digits and blocks of `b = L / count` digits, those numbers are the blocks of
+/// `b` digits times `base^(L-b) + ... + base^b + 1 = (base^L - 1) / (base^b - 1)`.
+fn copies(
+    (left, right): (usize, usize),
+    base: Base,
+    count: usize,
+    length: usize,
+) -> impl Iterator<Item = usize> {
+    let (blocks, factor) = if count >= 2 && length.is_multiple_of(count) {
+        let base = base.0 as u128;
+        let block = (length / count) as u32;
+        let factor = (base.pow(length as u32) - 1) / (base.pow(block) - 1);
+        let first = (left as u128).div_ceil(factor).max(base.pow(block - 1));
+        let last = (right as u128 / factor).min(base.pow(block) - 1);
+        (first..=last, factor)
+    } else {
+        (RangeInclusive::new(1, 0), 0)
+    };
+    blocks.map(move |b| (b * factor) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base(base: u32) -> Base {
+        Base::new(base).unwrap()
+    }
+
+    fn digits(value: usize, b: u32) -> Digits {
+        Digits::new(value, base(b))
+    }
+
+    #[test]
+    fn base_10() {
+        assert_eq!("123123", digits(123123, 10).to_string());
+        assert_eq!(3, digits(123123, 10).period());
+        assert_eq!(1, digits(1111, 10).period());
+        assert_eq!(5, digits(12121, 10).period());
+        assert!(digits(1111, 10).is_copies(2));
+        assert!(!digits(111, 10).is_copies(2));
+        assert!(digits(12321, 10).is_palindrome());
+        assert!(digits(0, 10).is_palindrome());
+    }
+
+    #[test]
+    fn base_2() {
+        // 45 = 101101: `101` twice.
+        let d = digits(45, 2);
+        assert_eq!("101101", d.to_string());
+        assert_eq!((3, 2), (d.period(), d.repetitions()));
+        assert!(d.is_palindrome());
+        assert_eq!(
+            vec![3, 10, 15, 36, 45, 54, 63],
+            Pattern::Copies(2).find(0..=63, base(2))
+        );
+    }
+
+    #[test]
+    fn base_16() {
+        let d = digits(0xabab, 16);
+        assert_eq!("abab", d.to_string());
+        assert_eq!(2, d.period());
+        assert!(!d.is_palindrome());
+        assert!(Pattern::RepeatedAtLeast(2).matches(&d));
+        assert!(!Pattern::RepeatedAtLeast(3).matches(&d));
+    }
+
+    #[test]
+    fn base_36() {
+        let d = digits(36 * 36 - 1, 36);
+        assert_eq!("zz", d.to_string());
+        assert_eq!((1, 2), (d.period(), d.repetitions()));
+        assert_eq!(35, Pattern::Copies(2).find(36..=1295, base(36)).len());
+        assert!(Base::new(37).is_err());
+        assert!(Base::new(1).is_err());
+    }
+
+    /// Whether the decimal text of `value` is `count` copies of one block, independently of
+    /// [`Digits`].
+    fn is_repeated(value: usize, count: usize) -> bool {
+        let text = value.to_string();
+        let size = text.len() / count;
+        text.len().is_multiple_of(count) && text == text[..size].repeat(count)
+    }
+
+    #[test]
+    fn decimal_numbers_match_their_text() {
+        for range in [1..=20_000, 95..=115, 998_000..=1_012_000, 7..=7] {
+            let scan = |counts: &[usize]| {
+                range
+                    .clone()
+                    .filter(|&x| counts.iter().any(|&c| is_repeated(x, c)))
+                    .collect::<Vec<_>>()
+            };
+            let find = |pattern: Pattern| pattern.find(range.clone(), Base::DECIMAL);
+            assert_eq!(scan(&[2]), find(Pattern::Copies(2)));
+            assert_eq!(scan(&[3]), find(Pattern::Copies(3)));
+            assert_eq!(scan(&[2, 3, 4, 5, 6, 7]), find(Pattern::RepeatedAtLeast(2)));
+        }
+    }
+
+    #[test]
+    fn found_numbers_match_a_scan() {
+        let patterns = [
+            Pattern::Copies(1),
+            Pattern::Copies(2),
+            Pattern::Copies(3),
+            Pattern::RepeatedAtLeast(2),
+            Pattern::RepeatedAtLeast(3),
+            Pattern::Palindrome,
+        ];
+        for b in [2, 3, 7, 10, 16, 36] {
+            for range in [0..=5000, 95..=115, 1000..=1000] {
+                for pattern in patterns {
+                    let scan = range
+                        .clone()
+                        .filter(|&x| pattern.matches(&digits(x, b)))
+                        .collect::<Vec<_>>();
+                    let found = pattern.find(range.clone(), base(b));
+                    assert_eq!(scan, found, "{:?} in base {} of {:?}", pattern, b, range);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn huge_ranges_cost_only_their_matches() {
+        // 9 numbers of 2 digits, 90 of 4 digits, ..., 900000 of 12 digits.
+        let found = Pattern::Copies(2).find(1..=999_999_999_999, Base::DECIMAL);
+        assert_eq!(999_999, found.len());
+        let huge = usize::MAX - 1_000_000_000_000..=usize::MAX;
+        let found = Pattern::RepeatedAtLeast(2).find(huge, Base::DECIMAL);
+        assert_eq!(100, found.len());
+        assert_eq!(Some(&18_446_744_071_844_674_407), found.last());
+        let found = Pattern::Copies(2).find(usize::MAX - 10..=usize::MAX, base(2));
+        assert_eq!(vec![usize::MAX], found);
+    }
+}
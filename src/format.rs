@@ -0,0 +1,229 @@
+use crate::answers::Verification;
+use crate::runner::{PartReport, print_summary};
+use anyhow::*;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// How the runner prints its reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Aligned table for humans.
+    #[default]
+    Table,
+    /// One JSON object per line.
+    Json,
+    /// Comma-separated values, with a header line.
+    Csv,
+    /// A Markdown table.
+    #[value(alias = "md")]
+    Markdown,
+}
+
+/// A report flattened into plain fields, shared by the machine-readable formats.
+#[derive(Debug, Serialize)]
+struct Row<'a> {
+    day: u8,
+    input: &'a str,
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    duration_ns: u64,
+    /// `pass`, `fail`, `unknown`, or `error` when the part produced no answer.
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<&'a str>,
+}
+
+impl<'a> From<&'a PartReport> for Row<'a> {
+    fn from(report: &'a PartReport) -> Self {
+        let (answer, error) = match &report.answer {
+            Result::Ok(answer) => (Some(answer.as_str()), None),
+            Err(e) => (None, Some(e.as_str())),
+        };
+        let (status, expected) = match (&report.answer, &report.verification) {
+            (Err(_), _) => ("error", None),
+            (_, Verification::Pass) => ("pass", None),
+            (_, Verification::Fail { expected }) => ("fail", Some(expected.as_str())),
+            (_, Verification::Unknown) => ("unknown", None),
+        };
+        Self {
+            day: report.day,
+            input: &report.input,
+            part: report.part.number(),
+            answer,
+            error,
+            duration_ns: report.duration.as_nanos().try_into().unwrap_or(u64::MAX),
+            status,
+            expected,
+        }
+    }
+}
+
+impl Row<'_> {
+    fn answer_or_error(&self) -> &str {
+        self.answer.or(self.error).unwrap_or_default()
+    }
+}
+
+/// Prints the reports to stdout in `format`.
+pub fn print_reports(reports: &[PartReport], format: Format) -> Result<()> {
+    let mut out = io::stdout().lock();
+    match format {
+        Format::Table => print_summary(reports),
+        Format::Json => write_json_lines(&mut out, reports)?,
+        Format::Csv => write_csv(&mut out, reports)?,
+        Format::Markdown => write_markdown(&mut out, reports)?,
+    }
+    Ok(())
+}
+
+pub fn write_json_lines<W: Write>(out: &mut W, reports: &[PartReport]) -> Result<()> {
+    for report in reports {
+        serde_json::to_writer(&mut *out, &Row::from(report))?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+pub fn write_csv<W: Write>(out: &mut W, reports: &[PartReport]) -> Result<()> {
+    writeln!(out, "day,input,part,answer,duration_ns,status,expected")?;
+    for row in reports.iter().map(Row::from) {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            row.day,
+            csv_field(row.input),
+            row.part,
+            csv_field(row.answer_or_error()),
+            row.duration_ns,
+            row.status,
+            csv_field(row.expected.unwrap_or_default())
+        )?;
+    }
+    Ok(())
+}
+
+pub fn write_markdown<W: Write>(out: &mut W, reports: &[PartReport]) -> Result<()> {
+    writeln!(out, "| Day | Input | Part | Answer | Time | Status |")?;
+    writeln!(out, "|----:|-------|-----:|--------|-----:|--------|")?;
+    for report in reports {
+        let row = Row::from(report);
+        let status = match row.status {
+            "error" => "ERROR".to_string(),
+            _ => report.verification.to_string(),
+        };
+        writeln!(
+            out,
+            "| {:0>2} | {} | {} | {} | {:?} | {} |",
+            row.day,
+            markdown_cell(row.input),
+            row.part,
+            markdown_cell(row.answer_or_error()),
+            report.duration,
+            markdown_cell(&status)
+        )?;
+    }
+    Ok(())
+}
+
+/// Quotes a field containing a separator, a quote or a line break (RFC 4180).
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Escapes pipes and flattens line breaks, which would otherwise break the table.
+fn markdown_cell(cell: &str) -> String {
+    cell.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Part;
+    use std::time::Duration;
+
+    fn reports() -> Vec<PartReport> {
+        vec![
+            PartReport {
+                day: 5,
+                input: "main".to_string(),
+                part: Part::One,
+                answer: Result::Ok("42".to_string()),
+                duration: Duration::from_micros(3),
+                verification: Verification::Fail {
+                    expected: "41".to_string(),
+                },
+            },
+            PartReport {
+                day: 5,
+                input: "main".to_string(),
+                part: Part::Two,
+                answer: Err("bad input, \"x\" | y".to_string()),
+                duration: Duration::ZERO,
+                verification: Verification::Unknown,
+            },
+        ]
+    }
+
+    fn render(write: fn(&mut Vec<u8>, &[PartReport]) -> Result<()>) -> Result<String> {
+        let mut out = Vec::new();
+        write(&mut out, &reports())?;
+        Ok(String::from_utf8(out)?)
+    }
+
+    #[test]
+    fn json_lines_hold_one_report_each() -> Result<()> {
+        let output = render(write_json_lines)?;
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(2, lines.len());
+        assert_eq!(
+            r#"{"day":5,"input":"main","part":1,"answer":"42","duration_ns":3000,"status":"fail","expected":"41"}"#,
+            lines[0]
+        );
+        let error: serde_json::Value = serde_json::from_str(lines[1])?;
+        assert_eq!("error", error["status"]);
+        assert_eq!("bad input, \"x\" | y", error["error"]);
+        Ok(())
+    }
+
+    #[test]
+    fn csv_quotes_special_fields() -> Result<()> {
+        assert_eq!(
+            "day,input,part,answer,duration_ns,status,expected\n\
+             5,main,1,42,3000,fail,41\n\
+             5,main,2,\"bad input, \"\"x\"\" | y\",0,error,\n",
+            render(write_csv)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn markdown_escapes_pipes() -> Result<()> {
+        let output = render(write_markdown)?;
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(
+            "| 05 | main | 1 | 42 | 3µs | FAIL (expected 41) |",
+            lines[2]
+        );
+        assert_eq!(
+            "| 05 | main | 2 | bad input, \"x\" \\| y | 0ns | ERROR |",
+            lines[3]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn format_from_str() {
+        let parse = |s| Format::from_str(s, false).ok();
+        assert_eq!(Some(Format::Markdown), parse("md"));
+        assert_eq!(Some(Format::Json), parse("json"));
+        assert_eq!(None, parse("xml"));
+    }
+}
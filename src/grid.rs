@@ -0,0 +1,330 @@
+use crate::parse;
+use anyhow::*;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+/// A `(row, column)` position, from the top-left corner.
+pub type Pos = (usize, usize);
+
+/// Up, right, down and left, as `(row, column)` offsets.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The 8 cells around a position, row by row.
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if let Some(row) = rows.iter().position(|r| r.len() != width) {
+            return Err(anyhow!(
+                "Row {} has {} cells, expected {}",
+                row + 1,
+                rows[row].len(),
+                width
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a map of chars, converting each one with `cell` (see [`parse::grid`]).
+    pub fn parse<R: BufRead>(
+        reader: R,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self> {
+        Self::from_rows(parse::grid(reader, expected, cell)?)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// `pos` moved by `(rows, columns)`, if still inside the grid.
+    pub fn offset(&self, (row, col): Pos, (rows, columns): (isize, isize)) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(rows)?,
+            col.checked_add_signed(columns)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The positions around `pos` at the given offsets, skipping those outside the grid.
+    pub fn neighbors<'a>(
+        &'a self,
+        pos: Pos,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets.iter().filter_map(move |&o| self.offset(pos, o))
+    }
+
+    /// The up to 4 orthogonal neighbors of `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &ORTHOGONAL)
+    }
+
+    /// The up to 8 neighbors of `pos`, diagonals included.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &SURROUNDING)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy on a grid without columns.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.width,
+            "column {} is outside of a {}x{} grid",
+            col,
+            self.width,
+            self.height
+        );
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, col)| (col, row))
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, col)| {
+            (self.height - 1 - col, row)
+        })
+    }
+
+    /// Rotates a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, col)| {
+            (col, self.width - 1 - row)
+        })
+    }
+
+    /// A `width` × `height` grid whose cell at `pos` is the cell of `self` at `source(pos)`.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Displays the grid one row per line, drawing each cell with `draw`.
+    pub fn display<F: Fn(&T) -> char>(&self, draw: F) -> GridDisplay<'_, T, F> {
+        GridDisplay { grid: self, draw }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of a {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", pos, width, height))
+    }
+}
+
+/// See [`Grid::display`].
+pub struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    draw: F,
+}
+
+impl<T, F: Fn(&T) -> char> Display for GridDisplay<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.rows() {
+            for cell in row {
+                write!(f, "{}", (self.draw)(cell))?;
+            }
+            writeln!(f)?;
+        }
+        Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Result<Grid<char>> {
+        Grid::parse("abc\ndef\n".as_bytes(), "a letter", |c| {
+            c.is_ascii_lowercase().then_some(c)
+        })
+    }
+
+    fn show(grid: &Grid<char>) -> String {
+        grid.display(|&c| c).to_string()
+    }
+
+    #[test]
+    fn parse_and_access() -> Result<()> {
+        let grid = sample()?;
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'f'), grid.get((1, 2)));
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(None, grid.get((0, 3)));
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!("be", grid.column(1).collect::<String>());
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(((1, 0), &'d'), grid.iter().nth(3).unwrap());
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "column 5 is outside of a 3x2 grid")]
+    fn column_outside_panics() {
+        let _ = sample().unwrap().column(5);
+    }
+
+    #[test]
+    fn neighbors_stay_inside() -> Result<()> {
+        let grid = sample()?;
+        let letters = |positions: Vec<Pos>| positions.iter().map(|&p| grid[p]).collect::<String>();
+        assert_eq!("bd", letters(grid.neighbors4((0, 0)).collect()));
+        assert_eq!("bfd", letters(grid.neighbors4((1, 1)).collect()));
+        assert_eq!("abcdf", letters(grid.neighbors8((1, 1)).collect()));
+        assert_eq!("bde", letters(grid.neighbors8((0, 0)).collect()));
+        Ok(())
+    }
+
+    #[test]
+    fn transpose_and_rotate() -> Result<()> {
+        let grid = sample()?;
+        assert_eq!("abc\ndef\n", show(&grid));
+        assert_eq!("ad\nbe\ncf\n", show(&grid.transpose()));
+        assert_eq!("da\neb\nfc\n", show(&grid.rotate_right()));
+        assert_eq!("cf\nbe\nad\n", show(&grid.rotate_left()));
+        assert_eq!(grid, grid.rotate_right().rotate_left());
+        assert_eq!(grid, grid.transpose().transpose());
+        Ok(())
+    }
+
+    #[test]
+    fn mutate_and_map() -> Result<()> {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(1, 0)] = 5;
+        *grid.get_mut((0, 1)).unwrap() = 3;
+        assert!(grid.get_mut((2, 2)).is_none());
+        assert_eq!(
+            "03\n50\n",
+            grid.display(|&v| char::from_digit(v, 10).unwrap())
+                .to_string()
+        );
+        assert_eq!(vec![0, 6, 10, 0], grid.map(|v| v * 2).cells);
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use crate::answers::Answers;
+use anyhow::*;
+use std::env;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory holding `NN.txt` input files.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Name of a day's own input, the one whose answers are stored in `answers/NN.toml`.
+pub const MAIN_INPUT: &str = "main";
+
+/// `$AOC_INPUT_DIR`, or `input` when unset.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("input"))
+}
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command-line argument: `-` is stdin, anything else a path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// The default input of `day`: `$AOC_INPUT_DIR/NN.txt`, or `input/NN.txt` when unset.
+    pub fn for_day(day: u8) -> Self {
+        InputSource::File(day_file(&input_dir(), day))
+    }
+
+    pub fn exists(&self) -> bool {
+        match self {
+            InputSource::File(path) => path.is_file(),
+            InputSource::Stdin => true,
+        }
+    }
+
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+            InputSource::File(path) => {
+                if !path.is_file() {
+                    return Err(anyhow!(
+                        "Input file not found: expected {} (set {} or pass --input)",
+                        absolute(path).display(),
+                        INPUT_DIR_VAR
+                    ));
+                }
+                let file =
+                    File::open(path).with_context(|| format!("Cannot open {}", path.display()))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// An input of a day together with the file holding its expected answers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedInput {
+    pub name: String,
+    pub source: InputSource,
+    /// Expected answers; a missing file means they are unknown.
+    pub answers: Option<PathBuf>,
+}
+
+impl NamedInput {
+    /// The day's own input, checked against `answers/NN.toml`.
+    pub fn main(day: u8) -> Self {
+        Self {
+            name: MAIN_INPUT.to_string(),
+            source: InputSource::for_day(day),
+            answers: Some(Answers::path(day)),
+        }
+    }
+
+    /// An ad-hoc input (`--input`), with no expected answers.
+    pub fn adhoc(source: InputSource) -> Self {
+        Self {
+            name: source.to_string(),
+            source,
+            answers: None,
+        }
+    }
+
+    pub fn load_answers(&self) -> Result<Answers> {
+        match &self.answers {
+            Some(path) => Answers::load_from(path),
+            None => Ok(Answers::default()),
+        }
+    }
+
+    /// Every input of `day`: `NN.txt` as `main`, plus each `NN/<name>.txt` with the
+    /// expected answers in `NN/<name>.toml`.
+    ///
+    /// `NN/main.txt` takes the place of `NN.txt`, and falls back to `answers/NN.toml`
+    /// when it has no answers file of its own.
+    pub fn discover(day: u8) -> Result<Vec<Self>> {
+        Self::discover_in(&input_dir(), day)
+    }
+
+    pub fn discover_in(dir: &Path, day: u8) -> Result<Vec<Self>> {
+        let day_dir = dir.join(format!("{:0>2}", day));
+        let mut inputs = Vec::new();
+        if day_dir.is_dir() {
+            let entries = fs::read_dir(&day_dir)
+                .with_context(|| format!("Cannot list {}", day_dir.display()))?;
+            for entry in entries {
+                let path = entry?.path();
+                if path.extension().is_none_or(|ext| ext != "txt") {
+                    continue;
+                }
+                let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                    continue;
+                };
+                let answers = path.with_extension("toml");
+                let answers = if name == MAIN_INPUT && !answers.exists() {
+                    Answers::path(day)
+                } else {
+                    answers
+                };
+                inputs.push(Self {
+                    name: name.to_string(),
+                    source: InputSource::File(path.clone()),
+                    answers: Some(answers),
+                });
+            }
+        }
+        inputs.sort_by(|a, b| a.name.cmp(&b.name));
+        if !inputs.iter().any(|i| i.name == MAIN_INPUT) {
+            let main = Self {
+                source: InputSource::File(day_file(dir, day)),
+                ..Self::main(day)
+            };
+            // Without any named input, keep `main` so the missing file gets reported.
+            if inputs.is_empty() || main.source.exists() {
+                inputs.insert(0, main);
+            }
+        }
+        Ok(inputs)
+    }
+}
+
+pub fn day_file(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("{:0>2}.txt", day))
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn from_arg_recognizes_stdin() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::File(PathBuf::from("other/05.txt")),
+            InputSource::from_arg("other/05.txt")
+        );
+    }
+
+    #[test]
+    fn missing_file_error_names_expected_path() {
+        let source = InputSource::File(day_file(Path::new("does-not-exist"), 5));
+        let error = source.open().err().unwrap().to_string();
+        assert!(error.contains("does-not-exist/05.txt"), "{}", error);
+        assert!(error.contains(INPUT_DIR_VAR), "{}", error);
+    }
+
+    #[test]
+    fn discover_lists_named_inputs_with_their_answers() -> Result<()> {
+        let dir = TempDir::new("discover");
+        fs::create_dir_all(dir.join("05"))?;
+        fs::write(dir.join("05.txt"), "1\n")?;
+        fs::write(dir.join("05/alice.txt"), "2\n")?;
+        fs::write(dir.join("05/alice.toml"), "part1 = 2\n")?;
+        fs::write(dir.join("05/notes.md"), "ignored\n")?;
+
+        let inputs = NamedInput::discover_in(dir.path(), 5)?;
+        let names = inputs.iter().map(|i| i.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec![MAIN_INPUT, "alice"], names);
+        assert_eq!(InputSource::File(dir.join("05.txt")), inputs[0].source);
+        assert_eq!(Some(Answers::path(5)), inputs[0].answers);
+        assert_eq!(Some("2"), inputs[1].load_answers()?.part1.as_deref());
+        Ok(())
+    }
+
+    #[test]
+    fn discover_keeps_missing_main_input() -> Result<()> {
+        let inputs = NamedInput::discover_in(Path::new("does-not-exist"), 5)?;
+        assert_eq!(1, inputs.len());
+        assert_eq!(MAIN_INPUT, inputs[0].name);
+        Ok(())
+    }
+}
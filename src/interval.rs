@@ -0,0 +1,315 @@
+//! Sets of integers stored as sorted, disjoint inclusive intervals.
+
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// The integer types an [`IntervalSet`] can hold.
+pub trait Integer: Copy + Ord + Debug {
+    /// The next integer, `None` on overflow.
+    fn succ(self) -> Option<Self>;
+
+    /// The previous integer, `None` on overflow.
+    fn pred(self) -> Option<Self>;
+
+    /// How many integers `start..=end` holds, `start <= end`.
+    fn span(start: Self, end: Self) -> u128;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn span(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers, kept as sorted intervals that neither overlap nor touch: inserting
+/// `3..=5` then `6..=8` stores the single interval `3..=8`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// Sorted `(start, end)` inclusive bounds, with at least one missing integer between
+    /// two consecutive intervals.
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every integer of `range`, merging the intervals it overlaps or touches.
+    ///
+    /// O(n) in the number of intervals, as the ones after `range` are shifted: build large
+    /// sets with `collect` instead.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Intervals ending before `start - 1`, then those starting up to `end + 1`.
+        let first = self
+            .intervals
+            .partition_point(|&(_, e)| e.succ().is_some_and(|after| after < start));
+        let last = self
+            .intervals
+            .partition_point(|&(s, _)| end.succ().is_none_or(|after| s <= after));
+        let merged = if first < last {
+            (
+                self.intervals[first].0.min(start),
+                self.intervals[last - 1].1.max(end),
+            )
+        } else {
+            (start, end)
+        };
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Whether `value` is in the set, by binary search.
+    pub fn contains(&self, value: T) -> bool {
+        let after = self.intervals.partition_point(|&(s, _)| s <= value);
+        after > 0 && value <= self.intervals[after - 1].1
+    }
+
+    /// Whether each of `values` is in the set, in the order given.
+    ///
+    /// Sorted values are walked along the intervals in a single linear sweep; others are
+    /// looked up one binary search at a time, which beats sorting them first.
+    pub fn contains_each(&self, values: &[T]) -> Vec<bool> {
+        if !values.is_sorted() {
+            return values.iter().map(|&value| self.contains(value)).collect();
+        }
+        let mut intervals = self.intervals.iter().peekable();
+        values
+            .iter()
+            .map(|&value| {
+                while intervals.next_if(|&&(_, end)| end < value).is_some() {}
+                intervals.peek().is_some_and(|&&(start, _)| start <= value)
+            })
+            .collect()
+    }
+
+    /// How many integers the set holds.
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(|&(s, e)| T::span(s, e)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The intervals of the set, in increasing order.
+    pub fn intervals(&self) -> impl ExactSizeIterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(s, e)| s..=e)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut merged = self.intervals.clone();
+        merged.extend_from_slice(&other.intervals);
+        merged.sort_unstable();
+        Self::from_sorted(merged)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) =
+            (self.intervals.get(i), other.intervals.get(j))
+        {
+            let (start, end) = (s1.max(s2), e1.min(e2));
+            if start <= end {
+                result.push((start, end));
+            }
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self::from_sorted(result)
+    }
+
+    /// The integers of `self` missing from `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        let mut j = 0;
+        for &(start, end) in &self.intervals {
+            let mut from = Some(start);
+            while let Some(current) = from
+                && let Some(&(s, e)) = other.intervals.get(j)
+                && s <= end
+            {
+                if e < current {
+                    j += 1;
+                    continue;
+                }
+                if s > current {
+                    // `s > current >= MIN`, so `s - 1` exists.
+                    result.push((current, s.pred().unwrap()));
+                }
+                if e >= end {
+                    // `other` may still overlap the next interval of `self`.
+                    from = None;
+                } else {
+                    from = e.succ();
+                    j += 1;
+                }
+            }
+            if let Some(current) = from {
+                result.push((current, end));
+            }
+        }
+        Self::from_sorted(result)
+    }
+
+    /// Builds a set from intervals sorted by start, merging those overlapping or touching.
+    fn from_sorted(sorted: Vec<(T, T)>) -> Self {
+        let mut intervals: Vec<(T, T)> = Vec::with_capacity(sorted.len());
+        for (start, end) in sorted {
+            match intervals.last_mut() {
+                Some((_, last)) if last.succ().is_none_or(|after| start <= after) => {
+                    *last = end.max(*last);
+                }
+                _ => intervals.push((start, end)),
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut sorted = ranges
+            .into_iter()
+            .map(RangeInclusive::into_inner)
+            .filter(|(start, end)| start <= end)
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+        Self::from_sorted(sorted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_adjacent() {
+        let mut set = set(&[1..=2, 10..=12, 6..=7]);
+        assert_eq!(
+            vec![1..=2, 6..=7, 10..=12],
+            set.intervals().collect::<Vec<_>>()
+        );
+        // Bridges two intervals that were disjoint until now.
+        set.insert(3..=9);
+        assert_eq!(vec![1..=12], set.intervals().collect::<Vec<_>>());
+        set.insert(RangeInclusive::new(14, 13));
+        assert_eq!(12, set.len());
+        assert!(set.contains(12) && !set.contains(13) && !set.contains(0));
+    }
+
+    #[test]
+    fn bounds_of_the_type_do_not_overflow() {
+        let mut set = IntervalSet::new();
+        set.insert(250u8..=255);
+        set.insert(0..=3);
+        set.insert(4..=4);
+        assert_eq!(vec![0..=4, 250..=255], set.intervals().collect::<Vec<_>>());
+        let full = [0u8..=255].into_iter().collect::<IntervalSet<_>>();
+        assert_eq!(256, full.len());
+        assert_eq!(
+            vec![5..=249],
+            full.difference(&set).intervals().collect::<Vec<_>>()
+        );
+        assert_eq!(set, full.intersection(&set));
+        assert_eq!(full, set.union(&full.difference(&set)));
+    }
+
+    fn brute(ranges: &[(i32, i32)]) -> BTreeSet<i32> {
+        ranges.iter().flat_map(|&(s, e)| s..=e).collect()
+    }
+
+    fn from_pairs(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().map(|&(s, e)| s..=e).collect()
+    }
+
+    fn members(set: &IntervalSet<i32>) -> BTreeSet<i32> {
+        set.intervals().flatten().collect()
+    }
+
+    fn is_normalized(set: &IntervalSet<i32>) -> bool {
+        set.intervals.iter().all(|(s, e)| s <= e)
+            && set.intervals.windows(2).all(|w| w[0].1 + 1 < w[1].0)
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<(i32, i32)>> {
+        prop::collection::vec((-50..50, 0..12).prop_map(|(s, l)| (s, s + l - 1)), 0..12)
+    }
+
+    proptest! {
+        #[test]
+        fn matches_a_set_of_integers(ranges in ranges()) {
+            let set = from_pairs(&ranges);
+            let expected = brute(&ranges);
+            prop_assert!(is_normalized(&set));
+            prop_assert_eq!(&expected, &members(&set));
+            prop_assert_eq!(expected.len() as u128, set.len());
+            let sorted = (-60..70).collect::<Vec<_>>();
+            let shuffled = (-60..70).rev().chain(-5..5).collect::<Vec<_>>();
+            for values in [sorted, shuffled] {
+                let each = set.contains_each(&values);
+                for (&x, &found) in values.iter().zip(&each) {
+                    prop_assert_eq!(expected.contains(&x), set.contains(x));
+                    prop_assert_eq!(expected.contains(&x), found);
+                }
+            }
+
+            let mut inserted = IntervalSet::new();
+            for &(s, e) in &ranges {
+                inserted.insert(s..=e);
+            }
+            prop_assert_eq!(set, inserted);
+        }
+
+        #[test]
+        fn set_operations_match(a in ranges(), b in ranges()) {
+            let (x, y) = (from_pairs(&a), from_pairs(&b));
+            let (bx, by) = (brute(&a), brute(&b));
+            for (result, expected) in [
+                (x.union(&y), bx.union(&by).copied().collect::<BTreeSet<_>>()),
+                (x.intersection(&y), bx.intersection(&by).copied().collect()),
+                (x.difference(&y), bx.difference(&by).copied().collect()),
+            ] {
+                prop_assert!(is_normalized(&result));
+                prop_assert_eq!(expected, members(&result));
+            }
+        }
+    }
+}
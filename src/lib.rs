@@ -1,7 +1,100 @@
+use crate::input::InputSource;
+use crate::visual::Recording;
+use anyhow::*;
+use code_timing_macros::time_snippet;
+use std::fmt::Display;
+use std::io::BufRead;
+
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod digits;
+pub mod format;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod parse;
+pub mod provider;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+#[cfg(test)]
+mod testing;
+pub mod visual;
+
 pub fn start_day(day: &str) {
     println!("Advent of Code 2025 - Day {:0>2}", day);
 }
 
+/// A day of the calendar: the input is parsed once, then shared by both parts.
+pub trait Solution {
+    /// Day number, zero-padded (`"01"`, `"02"`, ...).
+    const DAY: &'static str;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Frames showing how the state of the puzzle evolves, for the days that have one.
+    fn visualize(_input: &Self::Input) -> Option<Recording> {
+        None
+    }
+}
+
+/// Runs both parts of `S` against its default input file, timing each step.
+pub fn run<S: Solution>() -> Result<()> {
+    start_day(S::DAY);
+    let reader = InputSource::for_day(S::DAY.parse()?).open()?;
+    let input = time_snippet!(S::parse(reader)?);
+
+    //region Part 1
+    println!("=== Part 1 ===");
+    let result = time_snippet!(S::part1(&input)?);
+    println!("Result = {}", result);
+    //endregion
+
+    //region Part 2
+    println!("\n=== Part 2 ===");
+    let result = time_snippet!(S::part2(&input)?);
+    println!("Result = {}", result);
+    //endregion
+
+    Ok(())
+}
+
+/// Generates one `#[test]` per row, parsing the example input with the solution and
+/// checking the answer of the given part.
+///
+/// ```ignore
+/// example_tests! {
+///     Day01,
+///     part1_example: part1(TEST) == 3,
+///     part2_example: part2(TEST) == 6,
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, $($name:ident: $part:ident($input:expr) == $expected:expr),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() -> ::anyhow::Result<()> {
+                let reader = ::std::io::BufReader::new($input.as_bytes());
+                let input = <$solution as $crate::Solution>::parse(reader)?;
+                assert_eq!($expected, <$solution as $crate::Solution>::$part(&input)?);
+                ::std::result::Result::Ok(())
+            }
+        )+
+    };
+}
+
 // Additional common functions
 
 #[cfg(test)]
@@ -0,0 +1,529 @@
+//! Located parsing of the usual puzzle input shapes: numbers, ranges, tuples, blank-line
+//! sections, char grids and whitespace-aligned columns.
+//!
+//! Every reader here ignores the blank lines ending the input, often left when an input is
+//! pasted by hand; blank lines anywhere else are reported.
+
+use anyhow::*;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Invalid puzzle input, located at a 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text; empty when something is missing.
+    pub text: String,
+    /// What was expected instead, e.g. "a number".
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves an error found while parsing a single line to line `line` of the input.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Moves an error found while parsing a slice of a line `columns` further right.
+    pub fn shifted(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "`{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text`, found at `column` of `line`, as a number.
+pub fn number<T: FromStr>(text: &str, line: usize, column: usize) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(line, column, text, "a number"))
+}
+
+/// Splits `line` on `separator`, pairing each field with its 1-based column.
+pub fn fields(line: &str, separator: char) -> impl Iterator<Item = (usize, &str)> {
+    let mut column = 1;
+    line.split(separator).map(move |field| {
+        let start = column;
+        column += field.chars().count() + 1;
+        (start, field)
+    })
+}
+
+/// Parses an inclusive range `a-b` found at `column` of `line`.
+pub fn range<T: FromStr>(
+    text: &str,
+    line: usize,
+    column: usize,
+) -> Result<RangeInclusive<T>, ParseError> {
+    let Some((from, to)) = text.split_once('-') else {
+        return Err(ParseError::new(line, column, text, "a range like `a-b`"));
+    };
+    let start = number(from, line, column)?;
+    let end = number(to, line, column + from.chars().count() + 1)?;
+    Result::Ok(start..=end)
+}
+
+/// Parses a line of exactly `N` numbers separated by `separator`, like `7,1` or `1,2,3`.
+pub fn tuple<T: FromStr, const N: usize>(
+    text: &str,
+    separator: char,
+    line: usize,
+) -> Result<[T; N], ParseError> {
+    let values = fields(text, separator)
+        .map(|(column, field)| number(field, line, column))
+        .collect::<Result<Vec<T>, _>>()?;
+    values.try_into().map_err(|_| {
+        let expected = format!("{} numbers separated by `{}`", N, separator);
+        ParseError::new(line, 1, text, expected)
+    })
+}
+
+/// Parses every line of the input as a [`tuple`].
+pub fn tuples<T: FromStr, const N: usize, R: BufRead>(
+    reader: R,
+    separator: char,
+) -> Result<Vec<[T; N]>> {
+    let mut result = Vec::new();
+    for (i, line) in lines(reader)?.iter().enumerate() {
+        result.push(tuple(line, separator, i + 1)?);
+    }
+    Ok(result)
+}
+
+/// A field of the input with its location, see [`tokens`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+/// Splits the input on `separator` and line breaks, reading it a buffer at a time: only one
+/// field is held in memory, however long the lines are.
+pub fn tokens<R: BufRead>(reader: R, separator: u8) -> Tokens<R> {
+    Tokens {
+        reader,
+        separator,
+        line: 1,
+        column: 1,
+        done: false,
+        ahead: None,
+    }
+}
+
+/// See [`tokens`].
+pub struct Tokens<R> {
+    reader: R,
+    separator: u8,
+    line: usize,
+    column: usize,
+    done: bool,
+    /// The token following a run of blank lines, read to know whether the run ended the input.
+    ahead: Option<Token>,
+}
+
+impl<R: BufRead> Tokens<R> {
+    fn next_token(&mut self) -> Result<Option<Token>> {
+        if self.done {
+            return Ok(None);
+        }
+        let mut bytes = Vec::new();
+        let end = loop {
+            let buffer = self.reader.fill_buf()?;
+            if buffer.is_empty() {
+                self.done = true;
+                break None;
+            }
+            match buffer
+                .iter()
+                .position(|&b| b == self.separator || b == b'\n')
+            {
+                Some(i) => {
+                    bytes.extend_from_slice(&buffer[..i]);
+                    let end = buffer[i];
+                    self.reader.consume(i + 1);
+                    break Some(end);
+                }
+                None => {
+                    bytes.extend_from_slice(buffer);
+                    let read = buffer.len();
+                    self.reader.consume(read);
+                }
+            }
+        };
+        // Nothing after the last line break.
+        if end.is_none() && bytes.is_empty() && self.column == 1 {
+            return Ok(None);
+        }
+        if end == Some(b'\n') && bytes.last() == Some(&b'\r') {
+            bytes.pop();
+        }
+        let text = String::from_utf8(bytes).map_err(|e| {
+            let found = String::from_utf8_lossy(e.as_bytes());
+            ParseError::new(self.line, self.column, &found, "UTF-8 text")
+        })?;
+        let token = Token {
+            line: self.line,
+            column: self.column,
+            text,
+        };
+        if end == Some(b'\n') {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += token.text.chars().count() + 1;
+        }
+        Ok(Some(token))
+    }
+
+    /// The next token, skipping the blank lines that end the input.
+    fn next_kept(&mut self) -> Result<Option<Token>> {
+        if let Some(token) = self.ahead.take() {
+            return Ok(Some(token));
+        }
+        let Some(token) = self.next_token()? else {
+            return Ok(None);
+        };
+        if !self.is_blank_line(&token) {
+            return Ok(Some(token));
+        }
+        while let Some(next) = self.next_token()? {
+            if !self.is_blank_line(&next) {
+                // Blank lines followed by more input are reported, from the first one.
+                self.ahead = Some(next);
+                return Ok(Some(token));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Whether `token`, the last one read, fills a whole line with blanks.
+    fn is_blank_line(&self, token: &Token) -> bool {
+        token.column == 1 && (self.column == 1 || self.done) && token.text.trim().is_empty()
+    }
+}
+
+impl<R: BufRead> Iterator for Tokens<R> {
+    type Item = Result<Token>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_kept().transpose()
+    }
+}
+
+/// The lines of the input, without the blank lines ending it.
+pub fn lines<R: BufRead>(reader: R) -> Result<Vec<String>> {
+    let mut lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    Ok(lines)
+}
+
+/// A block of consecutive non-blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// 1-based number of the first line of the section in the input.
+    pub first_line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Section {
+    /// The lines of the section with their line number in the input.
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &str)> {
+        (self.first_line..).zip(self.lines.iter().map(String::as_str))
+    }
+}
+
+/// Splits the input into sections separated by one or more blank lines.
+pub fn sections<R: BufRead>(reader: R) -> Result<Vec<Section>> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut in_section = false;
+    for (i, line) in lines(reader)?.into_iter().enumerate() {
+        if line.trim().is_empty() {
+            in_section = false;
+        } else if in_section && let Some(section) = sections.last_mut() {
+            section.lines.push(line);
+        } else {
+            in_section = true;
+            sections.push(Section {
+                first_line: i + 1,
+                lines: vec![line],
+            });
+        }
+    }
+    Ok(sections)
+}
+
+/// Parses a rectangular map of chars, converting each one with `cell`.
+///
+/// `expected` describes the accepted chars, for the error raised when `cell` returns `None`.
+pub fn grid<T, R: BufRead>(
+    reader: R,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for (i, line) in lines(reader)?.iter().enumerate() {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(j, c)| {
+                cell(c).ok_or_else(|| ParseError::new(i + 1, j + 1, &c.to_string(), expected))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(first) = rows.first()
+            && first.len() != row.len()
+        {
+            let expected = format!("a row of {} cells", first.len());
+            return Err(ParseError::new(i + 1, 1, line, expected).into());
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// A block of text columns, delimited by columns holding only spaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    /// 1-based column where the block starts.
+    pub column: usize,
+    /// The block on each line, padded with spaces to the width of the block.
+    pub cells: Vec<String>,
+}
+
+/// Splits `lines` into blocks of whitespace-aligned columns, such as a table of numbers
+/// whose cells may be left or right aligned.
+pub fn columns(lines: &[String]) -> Vec<Column> {
+    let chars = lines
+        .iter()
+        .map(|l| l.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let width = chars.iter().map(Vec::len).max().unwrap_or(0);
+    let blank = |i: usize| chars.iter().all(|l| l.get(i).is_none_or(|&c| c == ' '));
+
+    let mut result = Vec::new();
+    let mut start = None;
+    for i in 0..=width {
+        match (start, i == width || blank(i)) {
+            (None, false) => start = Some(i),
+            (Some(from), true) => {
+                let cells = chars
+                    .iter()
+                    .map(|l| {
+                        (from..i)
+                            .map(|j| l.get(j).copied().unwrap_or(' '))
+                            .collect()
+                    })
+                    .collect();
+                result.push(Column {
+                    column: from + 1,
+                    cells,
+                });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_locates_the_error() {
+        let error = ParseError::new(1, 1, "X12", "L or R").at_line(4);
+        assert_eq!(
+            "line 4, column 1: expected L or R, found `X12`",
+            error.to_string()
+        );
+        let error = ParseError::new(2, 3, "", "a number").shifted(4);
+        assert_eq!(
+            "line 2, column 7: expected a number, found nothing",
+            error.to_string()
+        );
+    }
+
+    fn strings(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn fields_track_columns() -> Result<()> {
+        let fields = fields("11-22,95-115", ',').collect::<Vec<_>>();
+        assert_eq!(vec![(1, "11-22"), (7, "95-115")], fields);
+        assert_eq!(115, number::<u32>("115", 1, 10)?);
+        assert_eq!(
+            Err(ParseError::new(3, 10, "1x5", "a number")),
+            number::<u32>("1x5", 3, 10)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ranges_and_tuples() -> Result<()> {
+        assert_eq!(3..=15, range::<u32>("3-15", 1, 1)?);
+        assert_eq!(
+            Err(ParseError::new(2, 6, "1x", "a number")),
+            range::<u32>("12-1x", 2, 3)
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 4, "12", "a range like `a-b`")),
+            range::<u32>("12", 1, 4)
+        );
+        assert_eq!([162, 817, 812], tuple::<u32, 3>("162,817,812", ',', 1)?);
+        assert_eq!(
+            Err(ParseError::new(4, 1, "7,1", "3 numbers separated by `,`")),
+            tuple::<u32, 3>("7,1", ',', 4)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tuples_are_read_line_by_line() -> Result<()> {
+        assert_eq!(
+            vec![[7, 1], [11, 1]],
+            tuples::<u32, 2, _>("7,1\n11,1\n".as_bytes(), ',')?
+        );
+        let error = tuples::<u32, 2, _>("7,1\n11;1\n".as_bytes(), ',').unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected a number, found `11;1`",
+            error.to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tokens_stream_across_buffers() -> Result<()> {
+        // A tiny buffer splits fields across reads.
+        let reader = std::io::BufReader::with_capacity(3, "11-22,95-115\r\n7,\n".as_bytes());
+        let tokens = tokens(reader, b',')
+            .map(|t| t.map(|t| (t.line, t.column, t.text)))
+            .collect::<Result<Vec<_>>>()?;
+        let expected = [(1, 1, "11-22"), (1, 7, "95-115"), (2, 1, "7"), (2, 3, "")];
+        let expected = expected.map(|(line, column, text)| (line, column, text.to_string()));
+        assert_eq!(expected.to_vec(), tokens);
+        assert_eq!(1, super::tokens("no newline".as_bytes(), b',').count());
+        Ok(())
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() -> Result<()> {
+        let sections = sections("\n3-5\n10-14\n\n\n1\n".as_bytes())?;
+        assert_eq!(2, sections.len());
+        assert_eq!(
+            vec![(2, "3-5"), (3, "10-14")],
+            sections[0].numbered().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![(6, "1")], sections[1].numbered().collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn grid_is_rectangular() -> Result<()> {
+        let cell = |c| match c {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        assert_eq!(
+            vec![vec![true, false], vec![false, true]],
+            grid("@.\n.@\n".as_bytes(), "@ or .", cell)?
+        );
+        let error = grid("@.\n.x\n".as_bytes(), "@ or .", cell).unwrap_err();
+        assert_eq!(
+            "line 2, column 2: expected @ or ., found `x`",
+            error.to_string()
+        );
+        let error = grid("@.\n.\n".as_bytes(), "@ or .", cell).unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected a row of 2 cells, found `.`",
+            error.to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn trailing_blank_lines_are_ignored() -> Result<()> {
+        assert_eq!(vec!["a", " b"], lines("a\n b\n\n \n".as_bytes())?);
+        let cell = |c| (c == '@').then_some(1);
+        assert_eq!(vec![vec![1]], grid("@\n\n \n".as_bytes(), "@", cell)?);
+        let error = grid("@\n\n@\n".as_bytes(), "@", cell).unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected a row of 1 cells, found nothing",
+            error.to_string()
+        );
+        assert_eq!(
+            vec![[7, 1]],
+            tuples::<u32, 2, _>("7,1\n\n".as_bytes(), ',')?
+        );
+
+        let texts = |input: &'static str| {
+            tokens(input.as_bytes(), b',')
+                .map(|t| t.map(|t| (t.line, t.text)))
+                .collect::<Result<Vec<_>>>()
+        };
+        let kept = vec![(1, "1-2".to_string()), (1, "3-4".to_string())];
+        assert_eq!(kept, texts("1-2,3-4\n\n \n")?);
+        assert_eq!(kept, texts("1-2,3-4\n\n ")?);
+        let blank = vec![
+            (1, "1-2".to_string()),
+            (2, String::new()),
+            (3, "3-4".to_string()),
+        ];
+        assert_eq!(blank, texts("1-2\n\n3-4\n\n")?);
+        Ok(())
+    }
+
+    #[test]
+    fn columns_follow_blank_columns() {
+        let columns = columns(&strings(&["123 328  51", " 45 64  387", "*   +   *  "]));
+        assert_eq!(
+            vec![
+                Column {
+                    column: 1,
+                    cells: strings(&["123", " 45", "*  "]),
+                },
+                Column {
+                    column: 5,
+                    cells: strings(&["328", "64 ", "+  "]),
+                },
+                Column {
+                    column: 9,
+                    cells: strings(&[" 51", "387", "*  "]),
+                },
+            ],
+            columns
+        );
+    }
+}
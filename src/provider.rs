@@ -0,0 +1,126 @@
+use crate::client::AocClient;
+use crate::input::day_file;
+use anyhow::*;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Downloads the puzzle input of a day.
+pub trait Fetcher {
+    fn fetch(&self, day: u8) -> Result<String>;
+}
+
+impl Fetcher for AocClient {
+    fn fetch(&self, day: u8) -> Result<String> {
+        self.get(&format!("/day/{}/input", day))
+    }
+}
+
+/// Serves inputs from a local cache directory, fetching each missing one exactly once.
+pub struct InputProvider<F: Fetcher> {
+    cache_dir: PathBuf,
+    fetcher: F,
+}
+
+impl<F: Fetcher> InputProvider<F> {
+    pub fn new(cache_dir: impl Into<PathBuf>, fetcher: F) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            fetcher,
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        day_file(&self.cache_dir, day)
+    }
+
+    /// Returns the cached input of `day`, fetching and storing it first if needed.
+    ///
+    /// An empty file, such as the placeholder created by `scaffold`, counts as missing.
+    pub fn get(&self, day: u8) -> Result<PathBuf> {
+        let path = self.path(day);
+        if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+            return Ok(path);
+        }
+        let input = self.fetcher.fetch(day)?;
+        if input.trim().is_empty() {
+            return Err(anyhow!("Fetched an empty input for day {:0>2}", day));
+        }
+        fs::create_dir_all(&self.cache_dir)
+            .with_context(|| format!("Cannot create {}", self.cache_dir.display()))?;
+        write_private(&path, &input)?;
+        Ok(path)
+    }
+}
+
+/// Writes `content` to `path`, readable by the owner only: puzzle inputs are personal.
+///
+/// The file is written aside and then renamed, so an interrupted fetch never leaves a
+/// truncated input in the cache.
+fn write_private(path: &Path, content: &str) -> Result<()> {
+    let partial = path.with_extension("partial");
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(&partial)
+        .with_context(|| format!("Cannot write {}", partial.display()))?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&partial, path).with_context(|| format!("Cannot write {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MockServer, TempDir};
+
+    fn input_server() -> MockServer {
+        MockServer::start(|request| match request.path.as_str() {
+            "/2025/day/5/input" if request.header("Cookie") == Some("session=secret") => {
+                (200, "3-5\n\n5\n".to_string())
+            }
+            "/2025/day/5/input" => (400, "Puzzle inputs differ by user.".to_string()),
+            _ => (404, "Not found".to_string()),
+        })
+    }
+
+    #[test]
+    fn fetches_once_then_serves_from_cache() -> Result<()> {
+        let server = input_server();
+        let dir = TempDir::new("provider-cache");
+        let provider =
+            InputProvider::new(dir.path(), AocClient::with_base_url(&server.url, "secret"));
+
+        fs::write(provider.path(5), "")?;
+        let path = provider.get(5)?;
+        assert_eq!("3-5\n\n5\n", fs::read_to_string(&path)?);
+        assert_eq!(path, provider.get(5)?);
+        assert_eq!(1, server.requests().len());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(0o600, fs::metadata(&path)?.permissions().mode() & 0o777);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fetch_errors_leave_the_cache_empty() -> Result<()> {
+        let server = input_server();
+        let dir = TempDir::new("provider-error");
+        let provider =
+            InputProvider::new(dir.path(), AocClient::with_base_url(&server.url, "wrong"));
+
+        assert!(provider.get(5).is_err());
+        assert!(provider.get(6).is_err());
+        assert!(!provider.path(5).exists());
+        Ok(())
+    }
+}
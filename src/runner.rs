@@ -0,0 +1,239 @@
+use crate::Solution;
+use crate::answers::Verification;
+use crate::bench::{BenchConfig, BenchResult, Timings, bench_solution};
+use crate::input::NamedInput;
+use crate::visual::Recording;
+use anyhow::*;
+use std::fmt::Display;
+use std::io::{BufRead, Read};
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(number: u8) -> Result<Self> {
+        match number {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(anyhow!("Invalid part {}, expected 1 or 2", number)),
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// Outcome of one part of one day.
+#[derive(Debug)]
+pub struct PartReport {
+    pub day: u8,
+    /// Name of the input the part ran against.
+    pub input: String,
+    pub part: Part,
+    /// The displayed answer, or the error message if the day failed.
+    pub answer: std::result::Result<String, String>,
+    pub duration: Duration,
+    pub verification: Verification,
+}
+
+impl PartReport {
+    /// An answer differing from the confirmed one, or no answer at all.
+    pub fn is_failure(&self) -> bool {
+        self.answer.is_err() || matches!(self.verification, Verification::Fail { .. })
+    }
+}
+
+type Outcomes = Vec<(Part, String, Duration)>;
+
+/// A registered day, with its `Solution` erased so days can live in one list.
+pub struct Day {
+    pub number: u8,
+    run: fn(&mut dyn BufRead, &[Part]) -> Result<Outcomes>,
+    bench: fn(&str, &[Part], &BenchConfig) -> Result<Timings>,
+    visualize: fn(&mut dyn BufRead) -> Result<Option<Recording>>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        let number = S::DAY
+            .parse()
+            .unwrap_or_else(|_| panic!("DAY must be a number, got {:?}", S::DAY));
+        Self {
+            number,
+            run: run_parts::<S>,
+            bench: bench_solution::<S>,
+            visualize: visualize::<S>,
+        }
+    }
+
+    /// Runs the selected parts against `input`, checking them against its expected answers.
+    ///
+    /// Failures (missing input, parse error, ...) are reported per part instead of aborting,
+    /// so a whole season can be run even when one day is broken.
+    pub fn run(&self, input: &NamedInput, parts: &[Part]) -> Vec<PartReport> {
+        let results = input.load_answers().and_then(|answers| {
+            let outcomes = (self.run)(&mut input.source.open()?, parts)?;
+            Ok((answers, outcomes))
+        });
+        match results {
+            Result::Ok((answers, results)) => results
+                .into_iter()
+                .map(|(part, answer, duration)| PartReport {
+                    day: self.number,
+                    input: input.name.clone(),
+                    part,
+                    verification: answers.verify(part, &answer),
+                    answer: Result::Ok(answer),
+                    duration,
+                })
+                .collect(),
+            Err(e) => parts
+                .iter()
+                .map(|&part| PartReport {
+                    day: self.number,
+                    input: input.name.clone(),
+                    part,
+                    answer: Err(format!("{:#}", e)),
+                    duration: Duration::ZERO,
+                    verification: Verification::Unknown,
+                })
+                .collect(),
+        }
+    }
+
+    /// Benchmarks parsing `input` then the selected parts, each step timed separately.
+    pub fn bench(
+        &self,
+        input: &NamedInput,
+        parts: &[Part],
+        config: &BenchConfig,
+    ) -> Result<Vec<BenchResult>> {
+        let mut text = String::new();
+        input.source.open()?.read_to_string(&mut text)?;
+        let results = (self.bench)(&text, parts, config)
+            .with_context(|| format!("Day {:0>2} ({})", self.number, input.name))?;
+        Ok(results
+            .into_iter()
+            .map(|(step, stats)| BenchResult {
+                day: self.number,
+                input: input.name.clone(),
+                step,
+                stats,
+            })
+            .collect())
+    }
+
+    /// Records how the state evolves while solving `input`.
+    pub fn visualize(&self, input: &NamedInput) -> Result<Recording> {
+        (self.visualize)(&mut input.source.open()?)
+            .with_context(|| format!("Day {:0>2} ({})", self.number, input.name))?
+            .ok_or_else(|| anyhow!("Day {:0>2} has no visualization", self.number))
+    }
+}
+
+fn visualize<S: Solution>(reader: &mut dyn BufRead) -> Result<Option<Recording>> {
+    Ok(S::visualize(&S::parse(reader)?))
+}
+
+fn run_parts<S: Solution>(reader: &mut dyn BufRead, parts: &[Part]) -> Result<Outcomes> {
+    let input = S::parse(reader)?;
+    let mut results = Vec::new();
+    for &part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&input)?.to_string(),
+            Part::Two => S::part2(&input)?.to_string(),
+        };
+        results.push((part, answer, start.elapsed()));
+    }
+    Ok(results)
+}
+
+/// Parses a day selection: a single day (`5`) or an inclusive range (`3..7` or `3..=7`).
+pub fn parse_days(spec: &str) -> Result<RangeInclusive<u8>> {
+    let spec = spec.trim();
+    let range = match spec.split_once("..") {
+        Some((start, end)) => {
+            let end = end.strip_prefix('=').unwrap_or(end);
+            start.trim().parse()?..=end.trim().parse()?
+        }
+        None => {
+            let day = spec.parse()?;
+            day..=day
+        }
+    };
+    if range.is_empty() {
+        return Err(anyhow!("Empty day range: {}", spec));
+    }
+    Ok(range)
+}
+
+/// Prints the reports as an aligned table.
+pub fn print_summary(reports: &[PartReport]) {
+    let answers = reports
+        .iter()
+        .map(|r| match &r.answer {
+            Result::Ok(answer) => answer.clone(),
+            Err(e) => format!("error: {}", e),
+        })
+        .collect::<Vec<_>>();
+    let width = answers.iter().map(String::len).max().unwrap_or(0).max(6);
+    let input_width = reports
+        .iter()
+        .map(|r| r.input.len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    println!(
+        "Day  {:<input_width$}  Part  {:<width$}  {:<12}  Status",
+        "Input", "Answer", "Time"
+    );
+    for (report, answer) in reports.iter().zip(&answers) {
+        println!(
+            "{:0>2}   {:<input_width$}  {:>4}  {:<width$}  {:<12}  {}",
+            report.day,
+            report.input,
+            report.part,
+            answer,
+            format!("{:?}", report.duration),
+            report.verification
+        );
+    }
+    let total = reports.iter().map(|r| r.duration).sum::<Duration>();
+    println!("Total time: {:?}", total);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_days_accepts_single_day_and_ranges() -> Result<()> {
+        assert_eq!(5..=5, parse_days("5")?);
+        assert_eq!(3..=7, parse_days("3..7")?);
+        assert_eq!(3..=7, parse_days("3..=7")?);
+        assert!(parse_days("7..3").is_err());
+        assert!(parse_days("x").is_err());
+        Ok(())
+    }
+}
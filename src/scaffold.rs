@@ -0,0 +1,190 @@
+use crate::input::day_file;
+use anyhow::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The module every new day starts from.
+const MODULE_TEMPLATE: &str = include_str!("days/dayNN.rs");
+
+/// Generates the files of a new day inside a checkout of this repository.
+pub struct Scaffold {
+    root: PathBuf,
+    input_dir: PathBuf,
+}
+
+impl Scaffold {
+    pub fn new(root: impl Into<PathBuf>, input_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            input_dir: input_dir.into(),
+        }
+    }
+
+    /// Creates the module, binary, input and answers of `day`, and registers it in
+    /// `src/days/mod.rs`. Returns the files written.
+    ///
+    /// Nothing is written if the day already has a module or a binary; an input or
+    /// answers file already present is kept as is.
+    pub fn create(&self, day: u8) -> Result<Vec<PathBuf>> {
+        if !(1..=25).contains(&day) {
+            return Err(anyhow!("Invalid day {}, expected 1 to 25", day));
+        }
+        let module = self.root.join(format!("src/days/day{:0>2}.rs", day));
+        let binary = self.root.join(format!("src/bin/{:0>2}.rs", day));
+        for path in [&module, &binary] {
+            if path.exists() {
+                return Err(anyhow!(
+                    "Day {:0>2} already exists: refusing to overwrite {}",
+                    day,
+                    path.display()
+                ));
+            }
+        }
+        let registry = self.root.join("src/days/mod.rs");
+        let registered = register(
+            &fs::read_to_string(&registry)
+                .with_context(|| format!("Cannot read {}", registry.display()))?,
+            day,
+        )?;
+
+        let mut created = Vec::new();
+        let input = day_file(&self.input_dir, day);
+        let answers = self.root.join(format!("answers/{:0>2}.toml", day));
+        let files = [
+            (module, render_module(day), true),
+            (binary, render_binary(day), true),
+            (registry, registered, true),
+            (input, String::new(), false),
+            (answers, render_answers(day), false),
+        ];
+        for (path, content, overwrite) in files {
+            if !overwrite && path.exists() {
+                continue;
+            }
+            write(&path, &content)?;
+            created.push(path);
+        }
+        Ok(created)
+    }
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, content).with_context(|| format!("Cannot write {}", path.display()))
+}
+
+pub fn render_module(day: u8) -> String {
+    MODULE_TEMPLATE
+        .replace("DayNN", &format!("Day{:0>2}", day))
+        .replace("\"NN\"", &format!("\"{:0>2}\"", day))
+}
+
+fn render_binary(day: u8) -> String {
+    format!(
+        "use adv_code_2025::days::day{0:0>2}::Day{0:0>2};\n\
+         use adv_code_2025::*;\n\
+         use anyhow::*;\n\
+         \n\
+         fn main() -> Result<()> {{\n    run::<Day{0:0>2}>()\n}}\n",
+        day
+    )
+}
+
+fn render_answers(day: u8) -> String {
+    format!(
+        "# Confirmed answers of day {:0>2}, e.g.\n# part1 = 1234\n# part2 = \"5678\"\n",
+        day
+    )
+}
+
+/// Adds the module declaration and the `days::all()` entry of `day` to the content of
+/// `src/days/mod.rs`, both kept in calendar order.
+fn register(registry: &str, day: u8) -> Result<String> {
+    let declaration = format!("pub mod day{:0>2};", day);
+    let entry = format!("Day::of::<day{0:0>2}::Day{0:0>2}>(),", day);
+    let mut lines = registry.lines().map(str::to_string).collect::<Vec<_>>();
+    for (new, prefix) in [(&declaration, "pub mod day"), (&entry, "Day::of::<day")] {
+        let existing = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.trim_start().starts_with(prefix))
+            .collect::<Vec<_>>();
+        if existing.iter().any(|(_, line)| line.trim() == new.as_str()) {
+            return Err(anyhow!("Day {:0>2} is already registered", day));
+        }
+        let Some(&(last, last_line)) = existing.last() else {
+            return Err(anyhow!("No `{}` line found in src/days/mod.rs", prefix));
+        };
+        let indent = &last_line[..last_line.len() - last_line.trim_start().len()];
+        let position = existing
+            .iter()
+            .find(|(_, line)| line.trim() > new.as_str())
+            .map_or(last + 1, |&(i, _)| i);
+        lines.insert(position, format!("{}{}", indent, new));
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    const REGISTRY: &str = "\
+use crate::runner::Day;
+
+pub mod day01;
+pub mod day03;
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::of::<day01::Day01>(),
+        Day::of::<day03::Day03>(),
+    ]
+}
+";
+
+    #[test]
+    fn register_keeps_calendar_order() -> Result<()> {
+        let registry = register(REGISTRY, 2)?;
+        assert!(registry.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(registry.contains(
+            "        Day::of::<day01::Day01>(),\n        \
+             Day::of::<day02::Day02>(),\n        \
+             Day::of::<day03::Day03>(),\n"
+        ));
+        assert!(register(&register(REGISTRY, 10)?, 10).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn module_is_renamed() {
+        let module = render_module(12);
+        assert!(module.contains("pub struct Day12;"));
+        assert!(module.contains("const DAY: &'static str = \"12\";"));
+        assert!(!module.contains("NN"));
+    }
+
+    #[test]
+    fn create_writes_every_file_once() -> Result<()> {
+        let dir = TempDir::new("scaffold");
+        let root = dir.path();
+        write(&root.join("src/days/mod.rs"), REGISTRY)?;
+        write(&root.join("input/07.txt"), "fetched\n")?;
+        let scaffold = Scaffold::new(root, root.join("input"));
+
+        let created = scaffold.create(7)?;
+        assert_eq!(4, created.len());
+        assert!(root.join("src/days/day07.rs").is_file());
+        assert!(root.join("src/bin/07.rs").is_file());
+        assert!(root.join("answers/07.toml").is_file());
+        assert_eq!("fetched\n", fs::read_to_string(root.join("input/07.txt"))?);
+
+        let registry = fs::read_to_string(root.join("src/days/mod.rs"))?;
+        assert!(scaffold.create(7).is_err());
+        assert_eq!(registry, fs::read_to_string(root.join("src/days/mod.rs"))?);
+        Ok(())
+    }
+}
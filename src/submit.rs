@@ -0,0 +1,350 @@
+use crate::client::AocClient;
+use crate::runner::Part;
+use anyhow::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Delay imposed by the website after a wrong answer when its response does not say.
+const WRONG_ANSWER_DELAY: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted before the end of the previous delay; the answer was not checked.
+    TooRecent,
+    AlreadySolved,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooRecent => write!(f, "not checked, submitted too recently"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// How long to wait before the next submission is accepted.
+    pub wait: Duration,
+}
+
+impl Response {
+    /// Parses the page returned by the website after a submission.
+    pub fn parse(html: &str) -> Result<Self> {
+        let verdict = if html.contains("That's the right answer") {
+            Verdict::Correct
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if html.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if html.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if html.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            return Err(anyhow!("Unrecognized submission response: {}", html));
+        };
+
+        let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")?;
+        let minutes = Regex::new(r"(?i)wait (one|\d+) minutes?")?;
+        let wait = if let Some(c) = left.captures(html) {
+            let minutes = c.get(1).map_or(Result::Ok(0), |m| m.as_str().parse())?;
+            Duration::from_secs(minutes * 60 + c[2].parse::<u64>()?)
+        } else if let Some(c) = minutes.captures(html) {
+            let minutes = if &c[1] == "one" { 1 } else { c[1].parse()? };
+            Duration::from_secs(minutes * 60)
+        } else if matches!(verdict, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong) {
+            WRONG_ANSWER_DELAY
+        } else {
+            Duration::ZERO
+        };
+        Ok(Self { verdict, wait })
+    }
+}
+
+/// Sends an answer to the website.
+pub trait Submitter {
+    fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Response>;
+}
+
+impl Submitter for AocClient {
+    fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Response> {
+        let level = part.to_string();
+        let html = self.post_form(
+            &format!("/day/{}/answer", day),
+            &[("level", &level), ("answer", answer)],
+        )?;
+        Response::parse(&html)
+    }
+}
+
+/// What is known about the answers submitted for one part.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct PartHistory {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    wrong: Vec<String>,
+    /// Lowest answer known to be too high.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    too_high: Option<i64>,
+    /// Highest answer known to be too low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    too_low: Option<i64>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct History {
+    /// Unix time before which the website rejects submissions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next_allowed: Option<u64>,
+    #[serde(default)]
+    part1: PartHistory,
+    #[serde(default)]
+    part2: PartHistory,
+}
+
+impl History {
+    fn part(&self, part: Part) -> &PartHistory {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut PartHistory {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// Submits the answers of one day, refusing anything the history says is pointless:
+/// a value already rejected, a value beyond a known too-high/too-low bound, or a
+/// submission before the end of the delay imposed by the website.
+///
+/// The history is kept in [`history_path`].
+pub struct Submissions<S: Submitter> {
+    day: u8,
+    path: PathBuf,
+    submitter: S,
+    history: History,
+}
+
+/// `answers/NN.submissions.toml`
+pub fn history_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("answers/{:0>2}.submissions.toml", day))
+}
+
+impl<S: Submitter> Submissions<S> {
+    pub fn open(day: u8, submitter: S) -> Result<Self> {
+        Self::open_at(&history_path(day), day, submitter)
+    }
+
+    pub fn open_at(path: &Path, day: u8, submitter: S) -> Result<Self> {
+        let history = if path.exists() {
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("Cannot read {}", path.display()))?;
+            toml::from_str(&content)
+                .with_context(|| format!("Invalid submission history {}", path.display()))?
+        } else {
+            History::default()
+        };
+        Ok(Self {
+            day,
+            path: path.to_path_buf(),
+            submitter,
+            history,
+        })
+    }
+
+    /// Fails with the reason the answer should not be submitted at `now`, if any.
+    pub fn check(&self, part: Part, answer: &str, now: SystemTime) -> Result<()> {
+        let known = self.history.part(part);
+        if let Some(correct) = &known.correct {
+            return Err(anyhow!("Part {} is already solved: {}", part, correct));
+        }
+        if known.wrong.iter().any(|w| w == answer) {
+            return Err(anyhow!("{} was already rejected for part {}", answer, part));
+        }
+        if let Result::Ok(value) = answer.parse::<i64>() {
+            if let Some(high) = known.too_high.filter(|&high| value >= high) {
+                return Err(anyhow!("{} is too high: {} already was", answer, high));
+            }
+            if let Some(low) = known.too_low.filter(|&low| value <= low) {
+                return Err(anyhow!("{} is too low: {} already was", answer, low));
+            }
+        }
+        let now = unix_time(now);
+        if let Some(next) = self.history.next_allowed.filter(|&next| now < next) {
+            return Err(anyhow!("Wait {}s before submitting again", next - now));
+        }
+        Ok(())
+    }
+
+    pub fn submit(&mut self, part: Part, answer: &str) -> Result<Verdict> {
+        self.submit_at(part, answer, SystemTime::now())
+    }
+
+    /// Submits `answer` unless [`check`](Self::check) refuses it, then records the verdict.
+    pub fn submit_at(&mut self, part: Part, answer: &str, now: SystemTime) -> Result<Verdict> {
+        self.check(part, answer, now)?;
+        let response = self.submitter.submit(self.day, part, answer)?;
+        self.record(part, answer, response, now);
+        self.save()?;
+        Ok(response.verdict)
+    }
+
+    fn record(&mut self, part: Part, answer: &str, response: Response, now: SystemTime) {
+        if !response.wait.is_zero() {
+            self.history.next_allowed = Some(unix_time(now) + response.wait.as_secs());
+        }
+        let known = self.history.part_mut(part);
+        let value = answer.parse::<i64>().ok();
+        match response.verdict {
+            Verdict::Correct => known.correct = Some(answer.to_string()),
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => {
+                known.wrong.push(answer.to_string());
+                match (response.verdict, value) {
+                    (Verdict::TooHigh, Some(value)) => {
+                        known.too_high = Some(known.too_high.map_or(value, |h| h.min(value)))
+                    }
+                    (Verdict::TooLow, Some(value)) => {
+                        known.too_low = Some(known.too_low.map_or(value, |l| l.max(value)))
+                    }
+                    _ => {}
+                }
+            }
+            Verdict::TooRecent | Verdict::AlreadySolved => {}
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, toml::to_string(&self.history)?)
+            .with_context(|| format!("Cannot write {}", self.path.display()))
+    }
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MockServer, TempDir};
+
+    const CORRECT: &str = "<article><p>That's the right answer!  You are one gold star closer to decorating the North Pole.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.</p></article>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.</p></article>";
+    const WRONG: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 38s left to wait.</p></article>";
+    const SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+    #[test]
+    fn parse_recognizes_every_response() -> Result<()> {
+        let parse = |html| Response::parse(html).map(|r| (r.verdict, r.wait.as_secs()));
+        assert_eq!((Verdict::Correct, 0), parse(CORRECT)?);
+        assert_eq!((Verdict::TooHigh, 60), parse(TOO_HIGH)?);
+        assert_eq!((Verdict::TooLow, 300), parse(TOO_LOW)?);
+        assert_eq!((Verdict::Wrong, 60), parse(WRONG)?);
+        assert_eq!((Verdict::TooRecent, 98), parse(TOO_RECENT)?);
+        assert_eq!((Verdict::AlreadySolved, 0), parse(SOLVED)?);
+        assert!(parse("<html>Log in</html>").is_err());
+        Ok(())
+    }
+
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn remembers_wrong_answers_and_bounds() -> Result<()> {
+        let server = MockServer::start(|request| {
+            let html = match request.body.as_str() {
+                "level=1&answer=500" => TOO_HIGH,
+                "level=1&answer=100" => TOO_LOW,
+                "level=1&answer=abc" => WRONG,
+                _ => CORRECT,
+            };
+            (200, html.to_string())
+        });
+        let dir = TempDir::new("submissions");
+        let path = dir.join("05.toml");
+        let client = || AocClient::with_base_url(&server.url, "secret");
+        let mut submissions = Submissions::open_at(&path, 5, client())?;
+
+        assert_eq!(
+            Verdict::TooHigh,
+            submissions.submit_at(Part::One, "500", at(0))?
+        );
+        assert!(submissions.submit_at(Part::One, "100", at(30)).is_err());
+        assert_eq!(
+            Verdict::TooLow,
+            submissions.submit_at(Part::One, "100", at(60))?
+        );
+        assert_eq!(
+            Verdict::Wrong,
+            submissions.submit_at(Part::One, "abc", at(400))?
+        );
+
+        // Reloaded from disk, the history refuses every known-wrong value.
+        let mut submissions = Submissions::open_at(&path, 5, client())?;
+        for answer in ["500", "501", "100", "99", "abc"] {
+            assert!(submissions.submit_at(Part::One, answer, at(1000)).is_err());
+        }
+        assert_eq!(3, server.requests().len());
+        assert_eq!(
+            Verdict::Correct,
+            submissions.submit_at(Part::One, "250", at(1000))?
+        );
+        assert!(submissions.submit_at(Part::One, "250", at(2000)).is_err());
+        assert!(submissions.check(Part::Two, "250", at(2000)).is_ok());
+        assert_eq!("/2025/day/5/answer", server.requests()[0].path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_recent_delays_next_submission() -> Result<()> {
+        let server = MockServer::start(|_| (200, TOO_RECENT.to_string()));
+        let dir = TempDir::new("submissions-wait");
+        let path = dir.join("05.toml");
+        let client = AocClient::with_base_url(&server.url, "secret");
+        let mut submissions = Submissions::open_at(&path, 5, client)?;
+
+        assert_eq!(
+            Verdict::TooRecent,
+            submissions.submit_at(Part::Two, "7", at(0))?
+        );
+        let error = submissions.check(Part::Two, "7", at(90)).unwrap_err();
+        assert_eq!("Wait 8s before submitting again", error.to_string());
+        assert!(submissions.check(Part::Two, "7", at(98)).is_ok());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+//! Records the successive states of a simulation, to play them in the terminal or export
+//! them as images.
+
+use crate::grid::Grid;
+use anyhow::*;
+use clap::ValueEnum;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+pub type Rgb = [u8; 3];
+
+/// How a [`Recording`] is exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Export {
+    /// Animated in the terminal.
+    Ascii,
+    /// One PPM image per frame.
+    Ppm,
+    /// One PNG image per frame.
+    Png,
+    /// A single animated GIF.
+    Gif,
+}
+
+/// Colors of the chars used by the days' drawings.
+pub fn palette(c: char) -> Rgb {
+    match c {
+        '.' | ' ' => [16, 16, 24],
+        '@' | '#' => [210, 210, 210],
+        'x' => [255, 120, 0],
+        'S' => [0, 200, 80],
+        '^' => [220, 40, 40],
+        '|' => [255, 220, 0],
+        _ => [128, 128, 128],
+    }
+}
+
+/// Successive states of a simulation, each drawn as a grid of chars.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recording {
+    frames: Vec<Grid<char>>,
+}
+
+impl Recording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `grid` as the next frame, drawing each cell with `draw`.
+    pub fn record<T>(&mut self, grid: &Grid<T>, draw: impl Fn(&T) -> char) {
+        self.frames.push(grid.map(draw));
+    }
+
+    pub fn frames(&self) -> &[Grid<char>] {
+        &self.frames
+    }
+
+    /// Plays the frames in a terminal, redrawing the screen every `delay`.
+    pub fn play<W: Write>(&self, out: &mut W, delay: Duration) -> Result<()> {
+        for (i, frame) in self.frames.iter().enumerate() {
+            // Clear the screen and move the cursor to the top-left corner.
+            write!(out, "\x1b[2J\x1b[H")?;
+            write!(out, "{}", frame.display(|&c| c))?;
+            writeln!(out, "frame {}/{}", i + 1, self.frames.len())?;
+            out.flush()?;
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+
+    /// Writes one image per frame in `dir` (`frame_0000.ppm`, ...), each cell drawn as a
+    /// `scale` × `scale` square. Returns the files written.
+    pub fn save_images(
+        &self,
+        dir: &Path,
+        export: Export,
+        scale: usize,
+        palette: impl Fn(char) -> Rgb,
+    ) -> Result<Vec<PathBuf>> {
+        let extension = match export {
+            Export::Ppm => "ppm",
+            Export::Png => "png",
+            _ => return Err(anyhow!("{:?} does not export one image per frame", export)),
+        };
+        fs::create_dir_all(dir).with_context(|| format!("Cannot create {}", dir.display()))?;
+        let mut paths = Vec::new();
+        for (i, frame) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("frame_{:04}.{}", i, extension));
+            let mut out = BufWriter::new(
+                File::create(&path).with_context(|| format!("Cannot write {}", path.display()))?,
+            );
+            let image = Image::draw(frame, scale, &palette);
+            match export {
+                Export::Ppm => image.write_ppm(&mut out)?,
+                _ => image.write_png(&mut out)?,
+            }
+            out.flush()?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Writes every frame to an animated GIF, looping forever.
+    pub fn write_gif<W: Write>(
+        &self,
+        out: W,
+        scale: usize,
+        delay: Duration,
+        palette: impl Fn(char) -> Rgb,
+    ) -> Result<()> {
+        let Some(first) = self.frames.first() else {
+            return Err(anyhow!("Nothing recorded"));
+        };
+        // GIF frames index a palette of at most 256 colors, shared by the whole animation.
+        let mut colors: Vec<Rgb> = Vec::new();
+        let mut indices = HashMap::new();
+        for c in self.frames.iter().flat_map(|f| f.iter().map(|(_, &c)| c)) {
+            if let std::collections::hash_map::Entry::Vacant(entry) = indices.entry(c) {
+                let color = palette(c);
+                let index = colors
+                    .iter()
+                    .position(|&known| known == color)
+                    .unwrap_or_else(|| {
+                        colors.push(color);
+                        colors.len() - 1
+                    });
+                entry.insert(index as u8);
+            }
+        }
+        if colors.len() > 256 {
+            return Err(anyhow!(
+                "A GIF holds at most 256 colors, got {}",
+                colors.len()
+            ));
+        }
+
+        let width = u16::try_from(first.width() * scale)?;
+        let height = u16::try_from(first.height() * scale)?;
+        let global_palette = colors.concat();
+        let mut encoder = gif::Encoder::new(out, width, height, &global_palette)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for frame in &self.frames {
+            let indexed = frame.map(|c| indices[c]);
+            let gif_frame = gif::Frame {
+                width,
+                height,
+                delay: (delay.as_millis() / 10).try_into().unwrap_or(u16::MAX),
+                buffer: Cow::Owned(scaled(&indexed, scale)),
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&gif_frame)?;
+        }
+        Ok(())
+    }
+}
+
+/// Each cell of `grid` repeated as a `scale` × `scale` square, row by row.
+fn scaled<T: Copy>(grid: &Grid<T>, scale: usize) -> Vec<T> {
+    let mut pixels = Vec::with_capacity(grid.width() * grid.height() * scale * scale);
+    for row in grid.rows() {
+        for _ in 0..scale {
+            for &cell in row {
+                pixels.extend(std::iter::repeat_n(cell, scale));
+            }
+        }
+    }
+    pixels
+}
+
+struct Image {
+    width: usize,
+    height: usize,
+    /// RGB bytes, row by row.
+    data: Vec<u8>,
+}
+
+impl Image {
+    fn draw(frame: &Grid<char>, scale: usize, palette: impl Fn(char) -> Rgb) -> Self {
+        Self {
+            width: frame.width() * scale,
+            height: frame.height() * scale,
+            data: scaled(&frame.map(|&c| palette(c)), scale).concat(),
+        }
+    }
+
+    fn write_ppm<W: Write>(&self, out: &mut W) -> Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.data)?;
+        Ok(())
+    }
+
+    fn write_png<W: Write>(&self, out: &mut W) -> Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width.try_into()?, self.height.try_into()?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.data)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+    use std::io::Cursor;
+
+    fn recording() -> Result<Recording> {
+        let mut recording = Recording::new();
+        let grid = Grid::from_rows(vec![vec![true, false], vec![false, false]])?;
+        recording.record(&grid, |&b| if b { '@' } else { '.' });
+        recording.record(&grid, |&b| if b { 'x' } else { '.' });
+        Ok(recording)
+    }
+
+    #[test]
+    fn play_redraws_each_frame() -> Result<()> {
+        let mut out = Vec::new();
+        recording()?.play(&mut out, Duration::ZERO)?;
+        let out = String::from_utf8(out)?;
+        assert_eq!(2, out.matches("\x1b[2J").count());
+        assert!(out.contains("@.\n..\nframe 1/2\n"));
+        assert!(out.contains("x.\n..\nframe 2/2\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn ppm_scales_cells() -> Result<()> {
+        let recording = recording()?;
+        let frame = &recording.frames()[0];
+        let mut out = Vec::new();
+        Image::draw(frame, 2, palette).write_ppm(&mut out)?;
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(header, &out[..header.len()]);
+        let pixels = &out[header.len()..];
+        assert_eq!(4 * 4 * 3, pixels.len());
+        assert_eq!(palette('@'), pixels[3..6]);
+        assert_eq!(palette('.'), pixels[6..9]);
+        assert_eq!(palette('@'), pixels[12..15]);
+        Ok(())
+    }
+
+    #[test]
+    fn images_are_written_per_frame() -> Result<()> {
+        let dir = TempDir::new("visual");
+        let paths = recording()?.save_images(dir.path(), Export::Png, 3, palette)?;
+        assert_eq!(2, paths.len());
+        assert!(paths[1].ends_with("frame_0001.png"));
+
+        let decoder = png::Decoder::new(Cursor::new(fs::read(&paths[0])?));
+        let reader = decoder.read_info()?;
+        assert_eq!((6, 6), (reader.info().width, reader.info().height));
+        Ok(())
+    }
+
+    #[test]
+    fn gif_holds_every_frame() -> Result<()> {
+        let mut out = Vec::new();
+        recording()?.write_gif(&mut out, 2, Duration::from_millis(200), palette)?;
+
+        let mut decoder = gif::DecodeOptions::new().read_info(Cursor::new(out))?;
+        assert_eq!((4, 4), (decoder.width(), decoder.height()));
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame()? {
+            delays.push(frame.delay);
+        }
+        assert_eq!(vec![20, 20], delays);
+        Ok(())
+    }
+}